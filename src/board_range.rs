@@ -0,0 +1,127 @@
+//! The board-selection mini-language shared by `analyze` and `to_pdf`: which
+//! boards in a file to act on, expressed as a comma-separated list of
+//! numbers, ranges, open ranges, `odd`/`even`/`all` keywords, and `!N`
+//! exclusions.
+
+use anyhow::{Context, Result};
+
+/// Parse a board-selection expression against the board numbers actually
+/// present in the file, returning a resolved, de-duplicated, order-preserving
+/// set. Supports explicit numbers, inclusive ranges ("5-12"), open-ended
+/// ranges ("5-" through the last present board, "-8" from the first present
+/// board), `odd`/`even`/`all` keywords, and exclusions ("!7") which remove
+/// from whatever has been included so far. Terms are applied left to right,
+/// so a later exclusion overrides an earlier inclusion.
+pub fn parse_board_range(range: &str, present: &[u32]) -> Result<Vec<u32>> {
+    let mut selected: Vec<u32> = Vec::new();
+
+    for raw_term in range.split(',') {
+        let term = raw_term.trim();
+        if term.is_empty() {
+            continue;
+        }
+
+        if let Some(rest) = term.strip_prefix('!') {
+            let excluded = resolve_term(rest, present)?;
+            selected.retain(|n| !excluded.contains(n));
+        } else {
+            for n in resolve_term(term, present)? {
+                if !selected.contains(&n) {
+                    selected.push(n);
+                }
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+/// Resolve a single selection term (everything except a leading "!") against
+/// the boards actually present in the file.
+fn resolve_term(term: &str, present: &[u32]) -> Result<Vec<u32>> {
+    match term.to_ascii_lowercase().as_str() {
+        "all" => return Ok(present.to_vec()),
+        "odd" => return Ok(present.iter().copied().filter(|n| n % 2 == 1).collect()),
+        "even" => return Ok(present.iter().copied().filter(|n| n % 2 == 0).collect()),
+        _ => {}
+    }
+
+    if let Some(start_str) = term.strip_suffix('-') {
+        let start: u32 = start_str
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid number in open range: {}", term))?;
+        return Ok(present.iter().copied().filter(|&n| n >= start).collect());
+    }
+
+    if let Some(end_str) = term.strip_prefix('-') {
+        let end: u32 = end_str
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid number in open range: {}", term))?;
+        return Ok(present.iter().copied().filter(|&n| n <= end).collect());
+    }
+
+    if term.contains('-') {
+        let parts: Vec<&str> = term.splitn(2, '-').collect();
+        let start: u32 = parts[0]
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid number in range: {}", parts[0]))?;
+        let end: u32 = parts[1]
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid number in range: {}", parts[1]))?;
+        return Ok(present.iter().copied().filter(|&n| n >= start && n <= end).collect());
+    }
+
+    let num: u32 = term
+        .parse()
+        .with_context(|| format!("Invalid board number: {}", term))?;
+    Ok(present.iter().copied().filter(|&n| n == num).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_board_range() {
+        let present: Vec<u32> = (1..=12).collect();
+        assert_eq!(parse_board_range("1-4", &present).unwrap(), vec![1, 2, 3, 4]);
+        assert_eq!(parse_board_range("1,3,5", &present).unwrap(), vec![1, 3, 5]);
+        assert_eq!(parse_board_range("1-3,7", &present).unwrap(), vec![1, 2, 3, 7]);
+        assert_eq!(parse_board_range("1", &present).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_parse_board_range_open_ended() {
+        let present: Vec<u32> = (1..=12).collect();
+        assert_eq!(parse_board_range("10-", &present).unwrap(), vec![10, 11, 12]);
+        assert_eq!(parse_board_range("-3", &present).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_parse_board_range_keywords() {
+        let present: Vec<u32> = (1..=8).collect();
+        assert_eq!(parse_board_range("odd", &present).unwrap(), vec![1, 3, 5, 7]);
+        assert_eq!(parse_board_range("even", &present).unwrap(), vec![2, 4, 6, 8]);
+        assert_eq!(parse_board_range("all", &present).unwrap(), present);
+    }
+
+    #[test]
+    fn test_parse_board_range_exclusions() {
+        let present: Vec<u32> = (1..=16).collect();
+        assert_eq!(
+            parse_board_range("1-16,!7,!13", &present).unwrap(),
+            (1..=16).filter(|n| *n != 7 && *n != 13).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parse_board_range_skips_missing_numbers() {
+        // Board 5 doesn't exist in this file, so requesting "1-6" should drop it
+        let present: Vec<u32> = vec![1, 2, 3, 4, 6];
+        assert_eq!(parse_board_range("1-6", &present).unwrap(), vec![1, 2, 3, 4, 6]);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::board_range::parse_board_range;
 use anyhow::{Context, Result};
 use bridge_parsers::{Board, Direction, Vulnerability};
 use bridge_parsers::pbn::read_pbn;
@@ -6,6 +7,10 @@ use bridge_solver::{
     SPADE, WEST,
 };
 use clap::Args as ClapArgs;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(ClapArgs)]
@@ -18,17 +23,36 @@ pub struct Args {
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
-    /// Board range to analyze (e.g., "1-4" or "1,3,5")
+    /// Board selection (e.g., "1-4", "1,3,5", "5-", "-8", "odd", "even", "all",
+    /// or "1-16,!7,!13" to exclude specific boards)
     #[arg(short = 'r', long)]
     pub board_range: Option<String>,
 
     /// Show detailed output for each board
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Number of worker threads to solve with (defaults to rayon's own choice,
+    /// usually one per CPU core)
+    #[arg(short, long)]
+    pub threads: Option<usize>,
+
+    /// Write per-board results as structured JSON to this file, alongside
+    /// any --output PBN rewrite
+    #[arg(long)]
+    pub json: Option<PathBuf>,
+
+    /// Solve with boolean "can declarer take at least N tricks?" probes and
+    /// binary search instead of a single full-window search. Usually faster
+    /// since the tightened alpha-beta window produces more cutoffs, but it's
+    /// new, so it's opt-in until it's been cross-checked against the default
+    /// path on enough boards.
+    #[arg(long)]
+    pub binary_search: bool,
 }
 
 /// DD analysis results for a single board
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DdResults {
     /// Tricks by declarer (N, S, E, W) and denomination (NT, S, H, D, C)
     /// results[declarer_idx][denom_idx] = tricks
@@ -67,61 +91,172 @@ impl DdResults {
         output
     }
 
-    /// Get the par contract(s) and score
+    /// Tricks as a seat -> strain -> tricks map, for JSON output that names
+    /// every entry instead of relying on declarer/strain array order.
+    pub fn tricks_by_seat(&self) -> BTreeMap<&'static str, BTreeMap<&'static str, u8>> {
+        let seats = ["N", "S", "E", "W"];
+        let strains = ["NT", "S", "H", "D", "C"];
+        seats
+            .iter()
+            .enumerate()
+            .map(|(i, &seat)| {
+                let row = strains
+                    .iter()
+                    .enumerate()
+                    .map(|(j, &strain)| (strain, self.tricks[i][j]))
+                    .collect();
+                (seat, row)
+            })
+            .collect()
+    }
+
+    /// Get the par contract(s) and the NS-signed par score, computed as a
+    /// best-response fixed point over the competitive auction (see
+    /// `compute_par_score`/`par_contracts`).
     pub fn par_score(&self, vul_ns: bool, vul_ew: bool) -> (String, i32) {
-        // Find the best contract for each side
-        let (ns_contract, ns_score) = self.best_contract_for_side(true, vul_ns, vul_ew);
-        let (ew_contract, ew_score) = self.best_contract_for_side(false, vul_ew, vul_ns);
-
-        // The par is the result after competitive bidding
-        // If NS can make game and EW can't profitably sacrifice, NS plays game
-        // This is a simplified par calculation
-        if ns_score >= -ew_score {
-            (ns_contract, ns_score)
-        } else {
-            (ew_contract, -ew_score)
+        let par = self.compute_par_score(vul_ns, vul_ew);
+        (self.par_contracts(par, vul_ns, vul_ew).join(" or "), par)
+    }
+
+    /// The better of the two declarers on `side_ns`'s pair for `denom_idx`,
+    /// and the seat that achieves it. Ties keep the first seat (N over S,
+    /// E over W) so the result is deterministic.
+    fn best_declarer(&self, side_ns: bool, denom_idx: usize) -> (usize, u8) {
+        let declarers: [usize; 2] = if side_ns { [0, 1] } else { [2, 3] };
+        let mut best = (declarers[0], self.tricks[declarers[0]][denom_idx]);
+        let tricks = self.tricks[declarers[1]][denom_idx];
+        if tricks > best.1 {
+            best = (declarers[1], tricks);
         }
+        best
     }
 
-    fn best_contract_for_side(
+    /// NS-signed score if `side_ns` declares `level` of `denom_idx`, with the
+    /// defense doubling whenever that's worse for declarer than leaving it
+    /// undoubled. Also reports whether that double was taken.
+    fn contract_result(
         &self,
-        is_ns: bool,
-        declarer_vul: bool,
-        _defender_vul: bool,
-    ) -> (String, i32) {
-        let declarers: &[usize] = if is_ns { &[0, 1] } else { &[2, 3] };
-        let denoms = ["NT", "S", "H", "D", "C"];
+        level: u8,
+        denom_idx: usize,
+        side_ns: bool,
+        vul_ns: bool,
+        vul_ew: bool,
+    ) -> (i32, bool) {
+        let declarer_vul = if side_ns { vul_ns } else { vul_ew };
+        let (_, tricks) = self.best_declarer(side_ns, denom_idx);
+        let undoubled = contract_score(tricks, level, denom_idx, declarer_vul, false);
+        let doubled = contract_score(tricks, level, denom_idx, declarer_vul, true);
+        let (declarer_score, is_doubled) = if doubled < undoubled {
+            (doubled, true)
+        } else {
+            (undoubled, false)
+        };
+        let ns_score = if side_ns { declarer_score } else { -declarer_score };
+        (ns_score, is_doubled)
+    }
+
+    /// Simulate the competitive auction as a ladder of contracts ranked by
+    /// strain (C < D < H < S < NT) with level dominating. Scanning the ladder
+    /// bottom to top and letting whichever side can strictly improve on the
+    /// standing NS-perspective score take over the contract reaches the same
+    /// fixed point as repeatedly re-checking every contract for a profitable
+    /// move, since a real auction can never return to a rank it has passed.
+    fn compute_par_score(&self, vul_ns: bool, vul_ew: bool) -> i32 {
+        let mut current = 0;
+        for rank in 0..35u8 {
+            let level = rank / 5 + 1;
+            let denom_idx = (4 - rank % 5) as usize;
+
+            let (ns_score, _) = self.contract_result(level, denom_idx, true, vul_ns, vul_ew);
+            if ns_score > current {
+                current = ns_score;
+                continue;
+            }
+
+            let (ew_ns_score, _) = self.contract_result(level, denom_idx, false, vul_ns, vul_ew);
+            if ew_ns_score < current {
+                current = ew_ns_score;
+            }
+        }
+        current
+    }
+
+    /// Every contract (declared by either side) that reaches the par score,
+    /// for display when several contracts tie.
+    fn par_contracts(&self, par: i32, vul_ns: bool, vul_ew: bool) -> Vec<String> {
+        let strains = ["NT", "S", "H", "D", "C"];
         let seats = ["N", "S", "E", "W"];
+        let mut contracts = Vec::new();
 
-        let mut best_contract = String::new();
-        let mut best_score = i32::MIN;
-
-        for &decl in declarers {
-            for (denom_idx, denom) in denoms.iter().enumerate() {
-                let tricks = self.tricks[decl][denom_idx];
-                // Try different contract levels
-                for level in 1..=7 {
-                    let required = level + 6;
-                    if tricks >= required {
-                        let score = calculate_score(level, denom_idx, tricks, declarer_vul, false);
-                        if score > best_score {
-                            best_score = score;
-                            best_contract = format!("{}{} by {}", level, denom, seats[decl]);
-                        }
-                    }
+        if par == 0 {
+            contracts.push("Pass".to_string());
+        }
+
+        for rank in 0..35u8 {
+            let level = rank / 5 + 1;
+            let denom_idx = (4 - rank % 5) as usize;
+
+            for &side_ns in &[true, false] {
+                let (ns_score, doubled) =
+                    self.contract_result(level, denom_idx, side_ns, vul_ns, vul_ew);
+                if ns_score != par {
+                    continue;
+                }
+
+                let (seat, _) = self.best_declarer(side_ns, denom_idx);
+                let contract = if doubled {
+                    format!("{}{} X by {}", level, strains[denom_idx], seats[seat])
+                } else {
+                    format!("{}{} by {}", level, strains[denom_idx], seats[seat])
+                };
+                if !contracts.contains(&contract) {
+                    contracts.push(contract);
                 }
             }
         }
 
-        if best_contract.is_empty() {
-            best_contract = "Pass".to_string();
-            best_score = 0;
-        }
+        contracts
+    }
+}
 
-        (best_contract, best_score)
+/// Signed score for declarer's side if they bid `level` of `denom_idx` and
+/// take `tricks`: positive if the contract makes (see `calculate_score`),
+/// negative if it's defeated (see `undertrick_penalty`).
+fn contract_score(tricks: u8, level: u8, denom_idx: usize, vul: bool, doubled: bool) -> i32 {
+    let required = (level + 6) as i32;
+    let tricks = tricks as i32;
+    if tricks >= required {
+        calculate_score(level, denom_idx, tricks as u8, vul, doubled)
+    } else {
+        -undertrick_penalty((required - tricks) as u32, vul, doubled)
     }
 }
 
+/// Penalty conceded by declarer's side for going down `deficit` tricks.
+fn undertrick_penalty(deficit: u32, vul: bool, doubled: bool) -> i32 {
+    if !doubled {
+        return deficit as i32 * if vul { 100 } else { 50 };
+    }
+
+    (1..=deficit)
+        .map(|undertrick| {
+            if vul {
+                if undertrick == 1 {
+                    200
+                } else {
+                    300
+                }
+            } else if undertrick == 1 {
+                100
+            } else if undertrick <= 3 {
+                200
+            } else {
+                300
+            }
+        })
+        .sum()
+}
+
 /// Calculate the score for a made contract
 fn calculate_score(level: u8, denom_idx: usize, tricks: u8, vul: bool, doubled: bool) -> i32 {
     let overtricks = tricks as i32 - (level as i32 + 6);
@@ -188,7 +323,8 @@ pub fn run(args: Args) -> Result<()> {
 
     // Filter boards if range specified
     let boards: Vec<Board> = if let Some(ref range) = args.board_range {
-        let allowed = parse_board_range(range)?;
+        let present: Vec<u32> = boards.iter().filter_map(|b| b.number).collect();
+        let allowed = parse_board_range(range, &present)?;
         boards
             .into_iter()
             .filter(|b| b.number.map(|n| allowed.contains(&n)).unwrap_or(false))
@@ -201,56 +337,124 @@ pub fn run(args: Args) -> Result<()> {
         return Err(anyhow::anyhow!("No boards to analyze after filtering"));
     }
 
-    // Analyze each board
-    let mut results: Vec<(u32, DdResults)> = Vec::new();
+    // Analyze boards across a rayon work-pool: boards run concurrently, and
+    // within each board the five denominations are solved concurrently too,
+    // since each denomination owns its own caches and shares nothing mutable.
+    let pool = match args.threads {
+        Some(n) => Some(
+            ThreadPoolBuilder::new()
+                .num_threads(n)
+                .build()
+                .context("Failed to build thread pool")?,
+        ),
+        None => None,
+    };
 
-    for board in &boards {
-        let board_num = board.number.unwrap_or(0);
+    let use_binary_search = args.binary_search;
+    let analyze_all = || -> Vec<(u32, Vulnerability, Option<DdResults>)> {
+        boards
+            .par_iter()
+            .map(|board| {
+                let board_num = board.number.unwrap_or(0);
+                let dd_results =
+                    board_to_hands(board).map(|hands| analyze_deal(&hands, use_binary_search));
+                (board_num, board.vulnerable, dd_results)
+            })
+            .collect()
+    };
+    let mut computed = match &pool {
+        Some(pool) => pool.install(analyze_all),
+        None => analyze_all(),
+    };
 
-        // Convert deal to solver format
-        let hands = match board_to_hands(board) {
-            Some(h) => h,
+    // The pool may finish boards out of order; sort so add_dd_tags_to_pbn
+    // output stays stable regardless of completion order.
+    computed.sort_by_key(|(board_num, _, _)| *board_num);
+
+    let mut results: Vec<(u32, Vulnerability, DdResults)> = Vec::new();
+
+    for (board_num, vulnerable, dd_results) in computed {
+        let dd_results = match dd_results {
+            Some(r) => r,
             None => {
                 println!("Board {}: No deal found, skipping", board_num);
                 continue;
             }
         };
 
-        println!("Analyzing board {}...", board_num);
-
-        let dd_results = analyze_deal(&hands);
-
         if args.verbose {
             println!("Board {}:", board_num);
             println!("{}", dd_results.to_display_table());
 
-            // Show par if vulnerability is known
-            let (vul_ns, vul_ew) = match board.vulnerable {
-                Vulnerability::None => (false, false),
-                Vulnerability::NorthSouth => (true, false),
-                Vulnerability::EastWest => (false, true),
-                Vulnerability::Both => (true, true),
-            };
+            let (vul_ns, vul_ew) = vul_flags(vulnerable);
             let (par_contract, par_score) = dd_results.par_score(vul_ns, vul_ew);
             println!("  Par: {} ({})\n", par_contract, par_score);
         }
 
-        results.push((board_num, dd_results));
+        results.push((board_num, vulnerable, dd_results));
     }
 
     println!("Analyzed {} boards", results.len());
 
     // Write output PBN with DD tags if requested
     if let Some(output_path) = args.output {
-        let output_content = add_dd_tags_to_pbn(&content, &results)?;
+        let pbn_results: Vec<(u32, DdResults)> = results
+            .iter()
+            .map(|(board_num, _, dd)| (*board_num, dd.clone()))
+            .collect();
+        let output_content = add_dd_tags_to_pbn(&content, &pbn_results)?;
         std::fs::write(&output_path, output_content)
             .with_context(|| format!("Failed to write output file: {}", output_path.display()))?;
         println!("\nWrote PBN with DD results to {}", output_path.display());
     }
 
+    // Write structured JSON results if requested
+    if let Some(json_path) = args.json {
+        let report: Vec<BoardAnalysis> = results
+            .iter()
+            .map(|(board_num, vulnerable, dd)| {
+                let (vul_ns, vul_ew) = vul_flags(*vulnerable);
+                let (par_contract, par_score) = dd.par_score(vul_ns, vul_ew);
+                BoardAnalysis {
+                    board: *board_num,
+                    vulnerable: vulnerable.to_pbn(),
+                    tricks: dd.tricks_by_seat(),
+                    par_contract,
+                    par_score,
+                }
+            })
+            .collect();
+
+        let json_content =
+            serde_json::to_string_pretty(&report).context("Failed to serialize DD results as JSON")?;
+        std::fs::write(&json_path, json_content)
+            .with_context(|| format!("Failed to write JSON file: {}", json_path.display()))?;
+        println!("Wrote JSON DD results to {}", json_path.display());
+    }
+
     Ok(())
 }
 
+/// NS/EW vulnerability flags for scoring, derived from the PBN Vulnerable tag
+fn vul_flags(vulnerable: Vulnerability) -> (bool, bool) {
+    match vulnerable {
+        Vulnerability::None => (false, false),
+        Vulnerability::NorthSouth => (true, false),
+        Vulnerability::EastWest => (false, true),
+        Vulnerability::Both => (true, true),
+    }
+}
+
+/// One board's worth of DD analysis, shaped for the `--json` output file.
+#[derive(Serialize)]
+struct BoardAnalysis {
+    board: u32,
+    vulnerable: String,
+    tricks: BTreeMap<&'static str, BTreeMap<&'static str, u8>>,
+    par_contract: String,
+    par_score: i32,
+}
+
 /// Convert a Board's deal to solver Hands format
 fn board_to_hands(board: &Board) -> Option<Hands> {
     let deal = &board.deal;
@@ -277,32 +481,89 @@ fn board_to_hands(board: &Board) -> Option<Hands> {
     Hands::from_pbn(&pbn_deal)
 }
 
-/// Perform DD analysis on a deal
-fn analyze_deal(hands: &Hands) -> DdResults {
-    let declarers = [NORTH, SOUTH, EAST, WEST];
-    let denominations = [NOTRUMP, SPADE, HEART, DIAMOND, CLUB];
-    let mut results = [[0u8; 5]; 4];
+/// Find the largest `k` in `0..=num_tricks` for which `probe(k)` succeeds, in
+/// about `log2(num_tricks)` calls. `probe` must be monotonic: once it fails
+/// for some `k` it must fail for every larger `k` too.
+fn binary_search_max_reachable(num_tricks: u8, mut probe: impl FnMut(u8) -> bool) -> u8 {
+    let mut low = 0u8; // probe(0) always succeeds
+    let mut high = num_tricks;
+
+    while low < high {
+        // Round the midpoint up so `high` always shrinks on a failed probe,
+        // even when low and high are one apart.
+        let mid = low + (high - low + 1) / 2;
+        if probe(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    low
+}
 
-    for (denom_idx, &trump) in denominations.iter().enumerate() {
-        // Create caches once per denomination for efficiency
-        let mut cutoff_cache = CutoffCache::new(16);
-        let mut pattern_cache = PatternCache::new(16);
+/// Find the exact number of tricks NS can take by binary search over
+/// `Solver::solve_at_least`, the boolean variant that collapses the
+/// alpha-beta window to a single threshold and so cuts off far more nodes
+/// than a full-width search. Reuses the caller's caches across probes so
+/// transposition hits accumulate between them.
+fn solve_ns_tricks_by_binary_search(
+    solver: &Solver,
+    num_tricks: u8,
+    cutoff_cache: &mut CutoffCache,
+    pattern_cache: &mut PatternCache,
+) -> u8 {
+    binary_search_max_reachable(num_tricks, |target| {
+        solver.solve_at_least(target, cutoff_cache, pattern_cache)
+    })
+}
 
-        for (decl_idx, &declarer_seat) in declarers.iter().enumerate() {
-            // Leader is to the left of declarer
-            let leader = (declarer_seat + 1) % 4;
+/// Perform DD analysis on a deal, solving the five denominations concurrently
+fn analyze_deal(hands: &Hands, use_binary_search: bool) -> DdResults {
+    let declarers = [NORTH, SOUTH, EAST, WEST];
+    let denominations = [NOTRUMP, SPADE, HEART, DIAMOND, CLUB];
 
-            let solver = Solver::new(*hands, trump, leader);
-            let ns_tricks = solver.solve_with_caches(&mut cutoff_cache, &mut pattern_cache);
+    let by_denom: Vec<[u8; 4]> = denominations
+        .par_iter()
+        .map(|&trump| {
+            // Create caches once per denomination for efficiency
+            let mut cutoff_cache = CutoffCache::new(16);
+            let mut pattern_cache = PatternCache::new(16);
+            let mut declarer_tricks = [0u8; 4];
+            let num_tricks = hands.num_tricks() as u8;
+
+            for (decl_idx, &declarer_seat) in declarers.iter().enumerate() {
+                // Leader is to the left of declarer
+                let leader = (declarer_seat + 1) % 4;
+
+                let solver = Solver::new(*hands, trump, leader);
+                let ns_tricks = if use_binary_search {
+                    solve_ns_tricks_by_binary_search(
+                        &solver,
+                        num_tricks,
+                        &mut cutoff_cache,
+                        &mut pattern_cache,
+                    )
+                } else {
+                    solver.solve_with_caches(&mut cutoff_cache, &mut pattern_cache)
+                };
+
+                // Convert NS tricks to declarer's tricks
+                declarer_tricks[decl_idx] = if declarer_seat == NORTH || declarer_seat == SOUTH {
+                    ns_tricks
+                } else {
+                    num_tricks - ns_tricks
+                };
+            }
 
-            // Convert NS tricks to declarer's tricks
-            let declarer_tricks = if declarer_seat == NORTH || declarer_seat == SOUTH {
-                ns_tricks
-            } else {
-                hands.num_tricks() as u8 - ns_tricks
-            };
+            declarer_tricks
+        })
+        .collect();
 
-            results[decl_idx][denom_idx] = declarer_tricks;
+    let mut results = [[0u8; 5]; 4];
+    for (denom_idx, declarer_tricks) in by_denom.iter().enumerate() {
+        for (decl_idx, &tricks) in declarer_tricks.iter().enumerate() {
+            results[decl_idx][denom_idx] = tricks;
         }
     }
 
@@ -392,51 +653,10 @@ fn add_dd_tags_to_pbn(content: &str, results: &[(u32, DdResults)]) -> Result<Str
     Ok(output)
 }
 
-/// Parse a board range specification like "1-4" or "1,3,5" or "1-4,7,9-12"
-fn parse_board_range(range: &str) -> Result<Vec<u32>> {
-    let mut boards = Vec::new();
-
-    for part in range.split(',') {
-        let part = part.trim();
-        if part.contains('-') {
-            let parts: Vec<&str> = part.split('-').collect();
-            if parts.len() != 2 {
-                return Err(anyhow::anyhow!("Invalid range: {}", part));
-            }
-            let start: u32 = parts[0]
-                .trim()
-                .parse()
-                .with_context(|| format!("Invalid number in range: {}", parts[0]))?;
-            let end: u32 = parts[1]
-                .trim()
-                .parse()
-                .with_context(|| format!("Invalid number in range: {}", parts[1]))?;
-            for i in start..=end {
-                boards.push(i);
-            }
-        } else {
-            let num: u32 = part
-                .parse()
-                .with_context(|| format!("Invalid board number: {}", part))?;
-            boards.push(num);
-        }
-    }
-
-    Ok(boards)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_parse_board_range() {
-        assert_eq!(parse_board_range("1-4").unwrap(), vec![1, 2, 3, 4]);
-        assert_eq!(parse_board_range("1,3,5").unwrap(), vec![1, 3, 5]);
-        assert_eq!(parse_board_range("1-3,7").unwrap(), vec![1, 2, 3, 7]);
-        assert_eq!(parse_board_range("1").unwrap(), vec![1]);
-    }
-
     #[test]
     fn test_calculate_score() {
         // 3NT making exactly, not vul
@@ -448,4 +668,124 @@ mod tests {
         // 3NT with 2 overtricks, not vul
         assert_eq!(calculate_score(3, 0, 11, false, false), 460); // 100 + 300 + 60
     }
+
+    #[test]
+    fn test_undertrick_penalty_undoubled() {
+        assert_eq!(undertrick_penalty(1, false, false), 50);
+        assert_eq!(undertrick_penalty(3, false, false), 150);
+        assert_eq!(undertrick_penalty(1, true, false), 100);
+    }
+
+    #[test]
+    fn test_undertrick_penalty_doubled() {
+        // Not vulnerable: 100, 200, 200, 300, 300
+        assert_eq!(undertrick_penalty(1, false, true), 100);
+        assert_eq!(undertrick_penalty(3, false, true), 500);
+        assert_eq!(undertrick_penalty(5, false, true), 1100);
+
+        // Vulnerable: 200, 300, 300, ...
+        assert_eq!(undertrick_penalty(1, true, true), 200);
+        assert_eq!(undertrick_penalty(2, true, true), 500);
+    }
+
+    #[test]
+    fn test_contract_score_defeated_is_negative() {
+        // 4S down 2, vulnerable, doubled: -(200 + 300)
+        assert_eq!(contract_score(8, 4, 1, true, true), -500);
+    }
+
+    fn dd_results(tricks: [[u8; 5]; 4]) -> DdResults {
+        DdResults { tricks }
+    }
+
+    #[test]
+    fn test_par_score_is_pass_when_nobody_can_make_anything() {
+        let results = dd_results([[6; 5]; 4]);
+        let (contract, score) = results.par_score(false, false);
+        assert_eq!(contract, "Pass");
+        assert_eq!(score, 0);
+    }
+
+    #[test]
+    fn test_par_score_picks_the_making_game_for_the_side_that_has_one() {
+        // NS makes 3NT (9 tricks) in everything, EW can never make anything.
+        let mut tricks = [[6u8; 5]; 4];
+        tricks[0] = [9, 9, 9, 9, 9]; // North
+        tricks[1] = [9, 9, 9, 9, 9]; // South
+        let results = dd_results(tricks);
+
+        let (contract, score) = results.par_score(false, false);
+        assert_eq!(contract, "3NT by N");
+        assert_eq!(score, 400);
+    }
+
+    #[test]
+    fn test_par_score_rejects_an_ew_sacrifice_that_costs_more_than_the_ns_game() {
+        // NS makes 4S (10 tricks) for 620 vulnerable; EW can only manage 7
+        // tricks in any strain, so their cheapest sacrifice over 4S (4NT,
+        // down 3 doubled vulnerable) concedes 800, more than NS's game.
+        let mut tricks = [[7u8; 5]; 4];
+        tricks[0] = [6, 10, 6, 6, 6]; // North makes 4S
+        tricks[1] = [6, 10, 6, 6, 6]; // South makes 4S
+        let results = dd_results(tricks);
+
+        let (_, score) = results.par_score(true, true);
+        // Every sacrifice EW can try gets doubled for more than NS's 620, so
+        // par still favors NS playing their game.
+        assert_eq!(score, 620);
+    }
+
+    #[test]
+    fn test_tricks_by_seat_keys_match_declarer_and_strain_order() {
+        let mut tricks = [[0u8; 5]; 4];
+        tricks[0] = [9, 8, 7, 6, 5]; // North: NT S H D C
+        let results = dd_results(tricks);
+
+        let by_seat = results.tricks_by_seat();
+        assert_eq!(by_seat["N"]["NT"], 9);
+        assert_eq!(by_seat["N"]["S"], 8);
+        assert_eq!(by_seat["N"]["C"], 5);
+        assert_eq!(by_seat["S"]["NT"], 0);
+    }
+
+    #[test]
+    fn test_board_analysis_json_schema_is_stable() {
+        let tricks = [[9u8; 5]; 4];
+        let board = BoardAnalysis {
+            board: 7,
+            vulnerable: "NS".to_string(),
+            tricks: dd_results(tricks).tricks_by_seat(),
+            par_contract: "3NT by N".to_string(),
+            par_score: 400,
+        };
+
+        let json = serde_json::to_string(&board).unwrap();
+        assert!(json.contains("\"board\":7"));
+        assert!(json.contains("\"vulnerable\":\"NS\""));
+        assert!(json.contains("\"par_contract\":\"3NT by N\""));
+        assert!(json.contains("\"par_score\":400"));
+        assert!(json.contains("\"N\":{"));
+        assert!(json.contains("\"NT\":9"));
+    }
+
+    #[test]
+    fn test_binary_search_max_reachable_finds_exact_threshold() {
+        // Probe succeeds for every target up to and including 7.
+        let found = binary_search_max_reachable(13, |target| target <= 7);
+        assert_eq!(found, 7);
+    }
+
+    #[test]
+    fn test_binary_search_max_reachable_handles_extremes() {
+        assert_eq!(binary_search_max_reachable(13, |_| true), 13);
+        assert_eq!(binary_search_max_reachable(13, |target| target == 0), 0);
+    }
+
+    #[test]
+    fn test_binary_search_max_reachable_matches_linear_scan_for_every_threshold() {
+        for threshold in 0..=13u8 {
+            let found = binary_search_max_reachable(13, |target| target <= threshold);
+            assert_eq!(found, threshold, "mismatch for threshold {}", threshold);
+        }
+    }
 }
@@ -1,8 +1,9 @@
+use crate::deal_text::parse_deal;
+use crate::pbn_document::{PbnBoard, PbnDocument};
 use anyhow::{Context, Result};
 use bridge_parsers::{Direction, Vulnerability};
 use clap::Args as ClapArgs;
 use pbn_to_pdf::{config::Settings, parser::parse_pbn, render::generate_pdf};
-use regex::Regex;
 use std::path::PathBuf;
 
 #[derive(ClapArgs)]
@@ -29,7 +30,7 @@ pub struct Args {
 }
 
 /// Standard vulnerability pattern (repeats every 16 boards)
-const STANDARD_VUL: [Vulnerability; 16] = [
+pub(crate) const STANDARD_VUL: [Vulnerability; 16] = [
     Vulnerability::None,      // 1
     Vulnerability::NorthSouth, // 2
     Vulnerability::EastWest,  // 3
@@ -49,7 +50,7 @@ const STANDARD_VUL: [Vulnerability; 16] = [
 ];
 
 /// Standard dealer pattern (repeats every 4 boards)
-const STANDARD_DEALER: [Direction; 4] = [
+pub(crate) const STANDARD_DEALER: [Direction; 4] = [
     Direction::North,
     Direction::East,
     Direction::South,
@@ -64,21 +65,30 @@ pub fn run(args: Args) -> Result<()> {
     let content = std::fs::read_to_string(&args.input)
         .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
 
-    // Split content into header and board sections
-    let (header, board_sections) = split_pbn_content(&content);
+    // Parse the input into a structured document
+    let document = PbnDocument::parse(&content);
 
-    // Extract deal strings and BCFlags from each board section
-    let deal_strings: Vec<String> = board_sections
+    // Extract deal strings and BCFlags from each board
+    let deal_strings: Vec<String> = document
+        .boards
         .iter()
-        .map(|section| extract_tag_value(section, "Deal"))
+        .map(|board| board.tag("Deal").unwrap_or_default().to_string())
         .collect();
 
-    let bcflags_strings: Vec<String> = board_sections
+    let bcflags_strings: Vec<String> = document
+        .boards
         .iter()
-        .map(|section| extract_tag_value(section, "BCFlags"))
+        .map(|board| board.tag("BCFlags").unwrap_or_default().to_string())
         .collect();
 
-    let input_board_count = board_sections.len() as u32;
+    // A malformed source deal would otherwise get silently copied into every
+    // replicated block, so reject it up front with its board index.
+    for (index, deal_str) in deal_strings.iter().enumerate() {
+        parse_deal(deal_str)
+            .with_context(|| format!("Board {} has an invalid deal", index + 1))?;
+    }
+
+    let input_board_count = document.boards.len() as u32;
     println!(
         "Read {} boards from {}",
         input_board_count,
@@ -107,8 +117,7 @@ pub fn run(args: Args) -> Result<()> {
 
     // Generate the output content
     let output_content = generate_replicated_pbn(
-        &header,
-        &board_sections,
+        &document,
         &deal_strings,
         &bcflags_strings,
         block_size,
@@ -154,19 +163,15 @@ pub fn run(args: Args) -> Result<()> {
 
 /// Generate the replicated PBN content
 fn generate_replicated_pbn(
-    header: &str,
-    board_sections: &[String],
+    document: &PbnDocument,
     deal_strings: &[String],
     bcflags_strings: &[String],
     block_size: u32,
     block_count: u32,
 ) -> String {
-    let mut output = String::new();
+    let mut output = document.header.clone();
 
-    // Copy header
-    output.push_str(header);
-
-    let input_board_count = board_sections.len() as u32;
+    let input_board_count = document.boards.len() as u32;
 
     // Generate each board
     for bd in 0..(block_size * block_count) {
@@ -175,13 +180,7 @@ fn generate_replicated_pbn(
 
         // First block: preserve original boards with commentary (no virtual tags)
         if block_num == 0 && board_in_block < input_board_count {
-            // Copy original board content verbatim
-            if let Some(section) = board_sections.get(board_in_block as usize) {
-                output.push_str(section);
-                if !section.ends_with('\n') {
-                    output.push('\n');
-                }
-            }
+            output.push_str(&document.boards[board_in_block as usize].serialize());
             continue;
         }
 
@@ -197,107 +196,74 @@ fn generate_replicated_pbn(
         let virtual_dealer = STANDARD_DEALER[(board_in_block % 4) as usize];
         let virtual_vul = STANDARD_VUL[(board_in_block % 16) as usize];
 
-        // Get deal from source board section or use filler
-        let deal_str = if (board_in_block as usize) < deal_strings.len() {
-            deal_strings[board_in_block as usize].clone()
-        } else {
-            FILLER_DEAL.to_string()
-        };
+        // Get deal from source board or use filler
+        let deal_str = deal_strings
+            .get(board_in_block as usize)
+            .cloned()
+            .unwrap_or_else(|| FILLER_DEAL.to_string());
 
         // Get BCFlags from source board if available
-        let bcflags = if (board_in_block as usize) < bcflags_strings.len() {
-            bcflags_strings[board_in_block as usize].clone()
-        } else {
-            String::new()
-        };
+        let bcflags = bcflags_strings
+            .get(board_in_block as usize)
+            .cloned()
+            .unwrap_or_default();
 
         // Write board tags (PBN standard order)
-        output.push_str("[Event \"\"]\n");
-        output.push_str("[Site \"\"]\n");
-        output.push_str("[Date \"\"]\n");
-        output.push_str(&format!("[Board \"{}\"]\n", board_num));
-        output.push_str("[West \"\"]\n");
-        output.push_str("[North \"\"]\n");
-        output.push_str("[East \"\"]\n");
-        output.push_str("[South \"\"]\n");
-        output.push_str(&format!("[Dealer \"{}\"]\n", dealer.to_char()));
-        output.push_str(&format!("[Vulnerable \"{}\"]\n", vulnerable.to_pbn()));
-        output.push_str(&format!("[Deal \"{}\"]\n", deal_str));
-        output.push_str("[Scoring \"\"]\n");
-        output.push_str("[Declarer \"\"]\n");
-        output.push_str("[Contract \"\"]\n");
-        output.push_str("[Result \"\"]\n");
+        let mut board = PbnBoard::default();
+        board.set_tag("Event", "");
+        board.set_tag("Site", "");
+        board.set_tag("Date", "");
+        board.set_tag("Board", board_num.to_string());
+        board.set_tag("West", "");
+        board.set_tag("North", "");
+        board.set_tag("East", "");
+        board.set_tag("South", "");
+        board.set_tag("Dealer", dealer.to_char().to_string());
+        board.set_tag("Vulnerable", vulnerable.to_pbn());
+        board.set_tag("Deal", deal_str);
+        board.set_tag("Scoring", "");
+        board.set_tag("Declarer", "");
+        board.set_tag("Contract", "");
+        board.set_tag("Result", "");
 
         // Add BCFlags if present in original
         if !bcflags.is_empty() {
-            output.push_str(&format!("[BCFlags \"{}\"]\n", bcflags));
+            board.set_tag("BCFlags", bcflags);
         }
 
         // Add virtual board tags for tracking (only for replicated boards)
-        output.push_str(&format!("[VirtualBoard \"{}\"]\n", virtual_board));
-        output.push_str(&format!("[VirtualDealer \"{}\"]\n", virtual_dealer.to_char()));
-        output.push_str(&format!("[VirtualVulnerable \"{}\"]\n", virtual_vul.to_pbn()));
-        output.push_str(&format!("[BlockNumber \"{}\"]\n", block_num + 1));
+        board.set_tag("VirtualBoard", virtual_board.to_string());
+        board.set_tag("VirtualDealer", virtual_dealer.to_char().to_string());
+        board.set_tag("VirtualVulnerable", virtual_vul.to_pbn());
+        board.set_tag("BlockNumber", (block_num + 1).to_string());
 
+        output.push_str(&board.serialize());
         output.push('\n');
     }
 
     output
 }
 
-/// Split PBN content into header and individual board sections
-fn split_pbn_content(content: &str) -> (String, Vec<String>) {
-    let mut header = String::new();
-    let mut board_sections: Vec<String> = Vec::new();
-    let mut current_board = String::new();
-    let mut in_header = true;
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-
-        if in_header {
-            if trimmed.starts_with('%') || trimmed.is_empty() {
-                header.push_str(line);
-                header.push('\n');
-            } else if trimmed.starts_with('[') {
-                in_header = false;
-                current_board.push_str(line);
-                current_board.push('\n');
-            }
-        } else {
-            // Check if this is the start of a new board (Event tag typically starts a board)
-            if trimmed.starts_with("[Event ") && !current_board.is_empty() {
-                board_sections.push(std::mem::take(&mut current_board));
-            }
-            current_board.push_str(line);
-            current_board.push('\n');
-        }
-    }
-
-    // Don't forget the last board
-    if !current_board.is_empty() {
-        board_sections.push(current_board);
-    }
-
-    (header, board_sections)
-}
-
-/// Extract a tag value from a board section
-fn extract_tag_value(section: &str, tag_name: &str) -> String {
-    let pattern = format!(r#"\[{}\s+"([^"]+)"\]"#, tag_name);
-    let re = Regex::new(&pattern).unwrap();
-    if let Some(caps) = re.captures(section) {
-        caps.get(1)
-            .map(|m| m.as_str().to_string())
-            .unwrap_or_default()
-    } else {
-        String::new()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{assert_matches_fixture, read_fixture};
+
+    fn generate_from_fixture(name: &str, block_size: u32, block_count: u32) -> String {
+        let content = read_fixture("block_replicate", name);
+        let document = PbnDocument::parse(&content);
+        let deal_strings: Vec<String> = document
+            .boards
+            .iter()
+            .map(|b| b.tag("Deal").unwrap_or_default().to_string())
+            .collect();
+        let bcflags_strings: Vec<String> = document
+            .boards
+            .iter()
+            .map(|b| b.tag("BCFlags").unwrap_or_default().to_string())
+            .collect();
+        generate_replicated_pbn(&document, &deal_strings, &bcflags_strings, block_size, block_count)
+    }
 
     #[test]
     fn test_standard_vul_pattern() {
@@ -315,4 +281,52 @@ mod tests {
         assert_eq!(STANDARD_DEALER[2], Direction::South);
         assert_eq!(STANDARD_DEALER[3], Direction::West);
     }
+
+    #[test]
+    fn test_generate_replicated_pbn_copies_first_block_and_replicates_deal() {
+        let content = "[Event \"Club\"]\n[Board \"1\"]\n[Dealer \"N\"]\n[Deal \"N:AKQ.AKQ.AKQ.AKQT2 ...\"]\n";
+        let document = PbnDocument::parse(content);
+        let deal_strings: Vec<String> = document
+            .boards
+            .iter()
+            .map(|b| b.tag("Deal").unwrap_or_default().to_string())
+            .collect();
+        let bcflags_strings = vec![String::new()];
+
+        let output = generate_replicated_pbn(&document, &deal_strings, &bcflags_strings, 1, 2);
+
+        // Block 0 keeps the original board verbatim.
+        assert!(output.contains("[Event \"Club\"]"));
+        // Block 1 is a fresh replicated board carrying the same deal and virtual tags.
+        assert!(output.contains("[VirtualBoard \"1\"]"));
+        assert!(output.contains("[BlockNumber \"2\"]"));
+        assert!(output.contains("[Deal \"N:AKQ.AKQ.AKQ.AKQT2 ...\"]"));
+    }
+
+    #[test]
+    fn test_generate_replicated_pbn_fills_missing_boards_with_filler_deal() {
+        let document = PbnDocument::parse("[Event \"Club\"]\n[Board \"1\"]\n[Deal \"N:...\"]\n");
+        let deal_strings = vec!["N:...".to_string()];
+        let bcflags_strings = vec![String::new()];
+
+        let output = generate_replicated_pbn(&document, &deal_strings, &bcflags_strings, 2, 1);
+
+        assert!(output.contains(&format!("[Deal \"{}\"]", FILLER_DEAL)));
+    }
+
+    #[test]
+    fn test_generate_replicated_pbn_matches_basic_fixture() {
+        // Covers block numbering, virtual-tag emission, and BCFlags
+        // propagating from the source board into a replicated block.
+        let output = generate_from_fixture("basic", 2, 2);
+        assert_matches_fixture("block_replicate", "basic", &output);
+    }
+
+    #[test]
+    fn test_generate_replicated_pbn_matches_filler_fixture() {
+        // Covers filler-deal insertion when block_size exceeds the number
+        // of boards actually present in the input.
+        let output = generate_from_fixture("filler", 2, 1);
+        assert_matches_fixture("block_replicate", "filler", &output);
+    }
 }
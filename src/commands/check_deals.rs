@@ -0,0 +1,54 @@
+use crate::deal_text::parse_deal;
+use crate::pbn_document::PbnDocument;
+use anyhow::{anyhow, Context, Result};
+use clap::Args as ClapArgs;
+use std::path::PathBuf;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Input PBN file
+    #[arg(short, long)]
+    pub input: PathBuf,
+}
+
+pub fn run(args: Args) -> Result<()> {
+    let content = std::fs::read_to_string(&args.input)
+        .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
+    let document = PbnDocument::parse(&content);
+
+    let mut invalid = 0;
+    for (index, board) in document.boards.iter().enumerate() {
+        let deal_str = board.tag("Deal").unwrap_or_default();
+        if let Err(e) = parse_deal(deal_str) {
+            invalid += 1;
+            println!("board {}: invalid deal: {}", index + 1, e);
+        }
+    }
+
+    let valid = document.boards.len() - invalid;
+    println!("{} valid, {} invalid", valid, invalid);
+
+    if invalid > 0 {
+        return Err(anyhow!("{} board(s) have an invalid deal", invalid));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_reports_malformed_deals() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"1\"]\n[Deal \"N:AKQJT9876543... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432\"]\n",
+        );
+        let mut invalid = 0;
+        for board in &document.boards {
+            if parse_deal(board.tag("Deal").unwrap_or_default()).is_err() {
+                invalid += 1;
+            }
+        }
+        assert_eq!(invalid, 1);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::deal_text::{canonicalize_deal, parse_deal};
+use crate::pbn_document::PbnDocument;
+use anyhow::{Context, Result};
+use clap::Args as ClapArgs;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// First PBN file
+    #[arg(short, long)]
+    pub left: PathBuf,
+
+    /// Second PBN file
+    #[arg(short, long)]
+    pub right: PathBuf,
+}
+
+/// Tags compared (beyond the deal itself) once two boards are matched by
+/// canonical deal. Dealer/Vulnerable catch a replicator that mis-rotates a
+/// block; Contract catches a result that didn't survive a round trip.
+const COMPARED_TAGS: [&str; 3] = ["Dealer", "Vulnerable", "Contract"];
+
+/// A board's 1-indexed position plus the values of `COMPARED_TAGS`, grouped
+/// by canonical deal so boards can be matched without caring how they were
+/// numbered or in what order their tags appear.
+fn group_by_deal(document: &PbnDocument) -> Result<HashMap<String, Vec<(usize, Vec<String>)>>> {
+    let mut groups: HashMap<String, Vec<(usize, Vec<String>)>> = HashMap::new();
+    for (index, board) in document.boards.iter().enumerate() {
+        let deal_str = board
+            .tag("Deal")
+            .with_context(|| format!("board {} has no Deal tag", index + 1))?;
+        let deal = parse_deal(deal_str)
+            .with_context(|| format!("board {} has an invalid deal", index + 1))?;
+        let key = canonicalize_deal(&deal);
+        let tags = COMPARED_TAGS
+            .iter()
+            .map(|tag| board.tag(tag).unwrap_or("").to_string())
+            .collect();
+        groups.entry(key).or_default().push((index + 1, tags));
+    }
+    Ok(groups)
+}
+
+/// Iterate a deal-group map in board order (by each group's lowest board
+/// index), so diff output stays stable across runs regardless of the
+/// underlying HashMap's iteration order.
+fn sorted_groups(
+    groups: &HashMap<String, Vec<(usize, Vec<String>)>>,
+) -> Vec<(&String, &Vec<(usize, Vec<String>)>)> {
+    let mut sorted: Vec<_> = groups.iter().collect();
+    sorted.sort_by_key(|(_, entries)| entries.iter().map(|(board, _)| *board).min().unwrap_or(0));
+    sorted
+}
+
+pub fn run(args: Args) -> Result<()> {
+    let left_content = std::fs::read_to_string(&args.left)
+        .with_context(|| format!("Failed to read input file: {}", args.left.display()))?;
+    let right_content = std::fs::read_to_string(&args.right)
+        .with_context(|| format!("Failed to read input file: {}", args.right.display()))?;
+
+    let left_document = PbnDocument::parse(&left_content);
+    let right_document = PbnDocument::parse(&right_content);
+
+    let left_groups = group_by_deal(&left_document)?;
+    let right_groups = group_by_deal(&right_document)?;
+
+    let mut differences = 0;
+
+    for (_, entries) in sorted_groups(&left_groups) {
+        if entries.len() > 1 {
+            differences += 1;
+            let boards: Vec<String> = entries.iter().map(|(n, _)| n.to_string()).collect();
+            println!(
+                "{}: duplicate deal on boards {}",
+                args.left.display(),
+                boards.join(", ")
+            );
+        }
+    }
+    for (_, entries) in sorted_groups(&right_groups) {
+        if entries.len() > 1 {
+            differences += 1;
+            let boards: Vec<String> = entries.iter().map(|(n, _)| n.to_string()).collect();
+            println!(
+                "{}: duplicate deal on boards {}",
+                args.right.display(),
+                boards.join(", ")
+            );
+        }
+    }
+
+    for (deal, left_entries) in sorted_groups(&left_groups) {
+        match right_groups.get(deal) {
+            None => {
+                differences += 1;
+                for (board, _) in left_entries {
+                    println!("board {} ({}): present in {} only", board, deal, args.left.display());
+                }
+            }
+            Some(right_entries) => {
+                for (left_board, left_tags) in left_entries {
+                    for (right_board, right_tags) in right_entries {
+                        if left_tags != right_tags {
+                            differences += 1;
+                            println!(
+                                "board {} ({}) vs board {} ({}): {:?} vs {:?}",
+                                left_board,
+                                args.left.display(),
+                                right_board,
+                                args.right.display(),
+                                left_tags,
+                                right_tags
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for (deal, right_entries) in sorted_groups(&right_groups) {
+        if !left_groups.contains_key(deal) {
+            differences += 1;
+            for (board, _) in right_entries {
+                println!("board {} ({}): present in {} only", board, deal, args.right.display());
+            }
+        }
+    }
+
+    println!("{} difference(s)", differences);
+    if differences > 0 {
+        return Err(anyhow::anyhow!("{} difference(s) found", differences));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_deal_groups_matching_boards_across_starting_directions() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"1\"]\n[Deal \"N:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432\"]\n\
+             [Event \"Club\"]\n[Board \"2\"]\n[Deal \"E:.AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432 AKQJT98765432...\"]\n",
+        );
+        let groups = group_by_deal(&document).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups.values().next().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_group_by_deal_rejects_board_without_deal_tag() {
+        let document = PbnDocument::parse("[Event \"Club\"]\n[Board \"1\"]\n");
+        assert!(group_by_deal(&document).is_err());
+    }
+
+    #[test]
+    fn test_sorted_groups_orders_by_lowest_board_in_each_group() {
+        let mut groups: HashMap<String, Vec<(usize, Vec<String>)>> = HashMap::new();
+        groups.insert("deal-c".to_string(), vec![(5, vec![])]);
+        groups.insert("deal-a".to_string(), vec![(1, vec![]), (3, vec![])]);
+        groups.insert("deal-b".to_string(), vec![(2, vec![])]);
+
+        let order: Vec<&str> = sorted_groups(&groups)
+            .into_iter()
+            .map(|(deal, _)| deal.as_str())
+            .collect();
+
+        assert_eq!(order, vec!["deal-a", "deal-b", "deal-c"]);
+    }
+}
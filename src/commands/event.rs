@@ -1,6 +1,6 @@
+use crate::pbn_document::PbnDocument;
 use anyhow::{Context, Result};
 use clap::Args as ClapArgs;
-use regex::Regex;
 use std::path::PathBuf;
 
 #[derive(ClapArgs)]
@@ -28,12 +28,9 @@ pub fn run(args: Args) -> Result<()> {
         .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
 
     // Update all Event tags
-    let event_re = Regex::new(r#"\[Event\s+"[^"]*"\]"#).unwrap();
-    let new_event_tag = format!("[Event \"{}\"]", args.event);
-    let updated_content = event_re.replace_all(&content, new_event_tag.as_str());
-
-    // Count how many replacements were made
-    let match_count = event_re.find_iter(&content).count();
+    let mut document = PbnDocument::parse(&content);
+    let match_count = rename_events(&mut document, &args.event);
+    let updated_content = document.serialize();
 
     // Determine output path
     let output_path = if args.in_place {
@@ -47,7 +44,7 @@ pub fn run(args: Args) -> Result<()> {
     };
 
     // Write output
-    std::fs::write(&output_path, updated_content.as_ref())
+    std::fs::write(&output_path, &updated_content)
         .with_context(|| format!("Failed to write output file: {}", output_path.display()))?;
 
     // Report results
@@ -69,20 +66,51 @@ pub fn run(args: Args) -> Result<()> {
     Ok(())
 }
 
+/// Set every board's Event tag to `new_name`, leaving boards that have no
+/// Event tag untouched. Returns the number of boards updated.
+fn rename_events(document: &mut PbnDocument, new_name: &str) -> usize {
+    let mut match_count = 0;
+    for board in &mut document.boards {
+        if board.tag("Event").is_some() {
+            board.set_tag("Event", new_name);
+            match_count += 1;
+        }
+    }
+    match_count
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{assert_matches_fixture, read_fixture};
 
     #[test]
-    fn test_event_regex() {
-        let re = Regex::new(r#"\[Event\s+"[^"]*"\]"#).unwrap();
-        let content = r#"[Event "Old Event"]
-[Board "1"]
-[Event "Another"]"#;
-        let new_tag = "[Event \"New Event\"]";
-        let result = re.replace_all(content, new_tag);
+    fn test_run_rewrites_event_tag_on_every_board() {
+        let mut document = PbnDocument::parse(
+            "[Event \"Old Event\"]\n[Board \"1\"]\n\n[Event \"Old Event\"]\n[Board \"2\"]\n",
+        );
+        let match_count = rename_events(&mut document, "New Event");
+
+        assert_eq!(match_count, 2);
+        let result = document.serialize();
         assert!(result.contains("[Event \"New Event\"]"));
         assert!(!result.contains("Old Event"));
-        assert!(!result.contains("Another"));
+    }
+
+    #[test]
+    fn test_run_leaves_boards_without_an_event_tag_untouched() {
+        let mut document = PbnDocument::parse("[Board \"1\"]\n[Dealer \"N\"]\n");
+        let match_count = rename_events(&mut document, "New Event");
+
+        assert_eq!(match_count, 0);
+        assert_eq!(document.boards[0].tag("Event"), None);
+    }
+
+    #[test]
+    fn test_rename_events_matches_fixture() {
+        let content = read_fixture("event", "rename");
+        let mut document = PbnDocument::parse(&content);
+        rename_events(&mut document, "New Club Name");
+        assert_matches_fixture("event", "rename", &document.serialize());
     }
 }
@@ -4,6 +4,10 @@ use pbn_to_pdf::{config::Settings, parser::parse_pbn, render::generate_pdf};
 use regex::RegexBuilder;
 use std::path::PathBuf;
 
+mod query;
+
+use query::Query;
+
 #[derive(ClapArgs)]
 pub struct Args {
     /// Input PBN file
@@ -11,8 +15,12 @@ pub struct Args {
     pub input: PathBuf,
 
     /// Regex pattern to match against each board
-    #[arg(short = 'p', long)]
-    pub pattern: String,
+    #[arg(short = 'p', long, conflicts_with = "query")]
+    pub pattern: Option<String>,
+
+    /// Structured query over parsed board fields, e.g. "hcp(south) >= 25 and spades(north).len >= 6"
+    #[arg(short, long, conflicts_with = "pattern")]
+    pub query: Option<String>,
 
     /// Output file for matched boards (defaults to <input>-Matched.pbn if neither -m nor -n specified)
     #[arg(short = 'm', long)]
@@ -36,11 +44,24 @@ pub struct Args {
 }
 
 pub fn run(args: Args) -> Result<()> {
-    // Compile the regex pattern (case-insensitive by default, like the JS version)
-    let re = RegexBuilder::new(&args.pattern)
-        .case_insensitive(!args.case_sensitive)
-        .build()
-        .with_context(|| format!("Invalid regex pattern: {}", args.pattern))?;
+    // Build a matcher from either the regex pattern or the structured query;
+    // exactly one of the two must be given
+    let matcher: Box<dyn Fn(&str) -> bool> = match (&args.pattern, &args.query) {
+        (Some(pattern), None) => {
+            let re = RegexBuilder::new(pattern)
+                .case_insensitive(!args.case_sensitive)
+                .build()
+                .with_context(|| format!("Invalid regex pattern: {}", pattern))?;
+            Box::new(move |section: &str| re.is_match(section))
+        }
+        (None, Some(query_str)) => {
+            let query = Query::parse(query_str)
+                .with_context(|| format!("Invalid query: {}", query_str))?;
+            Box::new(move |section: &str| query.matches(section))
+        }
+        (None, None) => return Err(anyhow::anyhow!("Must specify either --pattern or --query")),
+        (Some(_), Some(_)) => unreachable!("clap enforces --pattern and --query are mutually exclusive"),
+    };
 
     // Read input file
     let content = std::fs::read_to_string(&args.input)
@@ -61,7 +82,7 @@ pub fn run(args: Args) -> Result<()> {
     let mut not_matched_boards = Vec::new();
 
     for section in &board_sections {
-        if re.is_match(section) {
+        if matcher(section) {
             matched_boards.push(section.clone());
         } else {
             not_matched_boards.push(section.clone());
@@ -132,7 +153,12 @@ pub fn run(args: Args) -> Result<()> {
 
     // Print summary
     println!();
-    println!("Filter results for pattern: {}", args.pattern);
+    let criteria = args
+        .pattern
+        .as_deref()
+        .or(args.query.as_deref())
+        .unwrap_or_default();
+    println!("Filter results for: {}", criteria);
     println!("  Boards scanned:     {}", total_boards);
     println!("  Boards matched:     {}", matched_count);
     println!("  Boards not matched: {}", not_matched_count);
@@ -0,0 +1,498 @@
+//! Structured query language for `wrangle filter --query`.
+//!
+//! Grammar:
+//!   expr    := or_expr
+//!   or_expr := and_expr ( "or" and_expr )*
+//!   and_expr:= unary ( "and" unary )*
+//!   unary   := "not" unary | "(" expr ")" | compare
+//!   compare := field op literal
+//!   field   := ident [ "(" ident ")" ] [ "." ident ]
+//!   op      := "==" | "!=" | ">=" | "<=" | "<" | ">"
+//!   literal := integer | ident
+
+use anyhow::{anyhow, Result};
+use pbn_to_pdf::model::{Board, Direction, Rank, Vulnerability};
+use pbn_to_pdf::parser::parse_pbn;
+
+/// A parsed `--query` expression, ready to be evaluated against board sections.
+pub struct Query {
+    expr: Expr,
+}
+
+impl Query {
+    pub fn parse(source: &str) -> Result<Query> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser { tokens: &tokens, pos: 0 };
+        let expr = parser.parse_expr()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(anyhow!("unexpected trailing input near token {}", parser.pos));
+        }
+        Ok(Query { expr })
+    }
+
+    /// Parse `section` as a single-board PBN fragment and evaluate the query against it.
+    /// Sections that fail to parse (e.g. an incomplete trailing fragment) never match.
+    pub fn matches(&self, section: &str) -> bool {
+        let Ok(pbn_file) = parse_pbn(section) else {
+            return false;
+        };
+        match pbn_file.boards.first() {
+            Some(board) => self.expr.eval(board),
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Compare(Field, CompareOp, Literal),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Ge,
+    Le,
+    Lt,
+    Gt,
+}
+
+#[derive(Debug, Clone)]
+enum Field {
+    Hcp(Direction),
+    SuitLen(Direction, SuitName),
+    ContractStrain,
+    Vul,
+    Dealer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SuitName {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    Int(i64),
+    Ident(String),
+}
+
+impl Expr {
+    fn eval(&self, board: &Board) -> bool {
+        match self {
+            Expr::And(l, r) => l.eval(board) && r.eval(board),
+            Expr::Or(l, r) => l.eval(board) || r.eval(board),
+            Expr::Not(e) => !e.eval(board),
+            Expr::Compare(field, op, lit) => eval_compare(field, *op, lit, board),
+        }
+    }
+}
+
+fn eval_compare(field: &Field, op: CompareOp, lit: &Literal, board: &Board) -> bool {
+    match field {
+        Field::Hcp(dir) => {
+            let Literal::Int(n) = lit else { return false };
+            compare_int(hcp(board, *dir) as i64, op, *n)
+        }
+        Field::SuitLen(dir, suit) => {
+            let Literal::Int(n) = lit else { return false };
+            compare_int(suit_len(board, *dir, *suit) as i64, op, *n)
+        }
+        Field::ContractStrain => {
+            let Literal::Ident(name) = lit else { return false };
+            match contract_strain_char(board) {
+                Some(c) => compare_eq(strain_matches(c, name), op),
+                None => false,
+            }
+        }
+        Field::Vul => {
+            let Literal::Ident(name) = lit else { return false };
+            compare_eq(vul_matches(board.vulnerable, name), op)
+        }
+        Field::Dealer => {
+            let Literal::Ident(name) = lit else { return false };
+            match board.dealer {
+                Some(dir) => compare_eq(direction_matches(dir, name), op),
+                None => false,
+            }
+        }
+    }
+}
+
+fn compare_int(actual: i64, op: CompareOp, expected: i64) -> bool {
+    match op {
+        CompareOp::Eq => actual == expected,
+        CompareOp::Ne => actual != expected,
+        CompareOp::Ge => actual >= expected,
+        CompareOp::Le => actual <= expected,
+        CompareOp::Lt => actual < expected,
+        CompareOp::Gt => actual > expected,
+    }
+}
+
+/// Enum-literal comparisons only support equality/inequality; any other
+/// operator is a query author error that we quietly treat as non-matching.
+fn compare_eq(matches: bool, op: CompareOp) -> bool {
+    match op {
+        CompareOp::Eq => matches,
+        CompareOp::Ne => !matches,
+        _ => false,
+    }
+}
+
+fn hcp(board: &Board, dir: Direction) -> u32 {
+    let hand = board.deal.hand(dir);
+    [&hand.spades, &hand.hearts, &hand.diamonds, &hand.clubs]
+        .iter()
+        .flat_map(|holding| holding.ranks.iter())
+        .map(|rank| rank_hcp(*rank))
+        .sum()
+}
+
+fn rank_hcp(rank: Rank) -> u32 {
+    match rank {
+        Rank::Ace => 4,
+        Rank::King => 3,
+        Rank::Queen => 2,
+        Rank::Jack => 1,
+        _ => 0,
+    }
+}
+
+fn suit_len(board: &Board, dir: Direction, suit: SuitName) -> usize {
+    let hand = board.deal.hand(dir);
+    let holding = match suit {
+        SuitName::Spades => &hand.spades,
+        SuitName::Hearts => &hand.hearts,
+        SuitName::Diamonds => &hand.diamonds,
+        SuitName::Clubs => &hand.clubs,
+    };
+    holding.ranks.len()
+}
+
+/// The strain of the final (highest, most recent) bid in the auction, as a
+/// LIN-style char: C/D/H/S/N.
+fn contract_strain_char(board: &Board) -> Option<char> {
+    use pbn_to_pdf::model::Call;
+    let auction = board.auction.as_ref()?;
+    auction.calls.iter().rev().find_map(|ac| match &ac.call {
+        Call::Bid { strain, .. } => Some(strain.to_char()),
+        _ => None,
+    })
+}
+
+fn strain_matches(c: char, name: &str) -> bool {
+    let name = name.to_ascii_uppercase();
+    match name.as_str() {
+        "NT" | "N" => c == 'N',
+        "S" => c == 'S',
+        "H" => c == 'H',
+        "D" => c == 'D',
+        "C" => c == 'C',
+        _ => false,
+    }
+}
+
+fn vul_matches(vul: Vulnerability, name: &str) -> bool {
+    match name.to_ascii_lowercase().as_str() {
+        "none" | "love" => vul == Vulnerability::None,
+        "ns" => vul == Vulnerability::NorthSouth,
+        "ew" => vul == Vulnerability::EastWest,
+        "both" | "all" => vul == Vulnerability::Both,
+        _ => false,
+    }
+}
+
+fn direction_matches(dir: Direction, name: &str) -> bool {
+    match name.to_ascii_lowercase().as_str() {
+        "north" | "n" => dir == Direction::North,
+        "east" | "e" => dir == Direction::East,
+        "south" | "s" => dir == Direction::South,
+        "west" | "w" => dir == Direction::West,
+        _ => false,
+    }
+}
+
+// --- Tokenizer -------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    LParen,
+    RParen,
+    Dot,
+    Op(CompareOp),
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '(' {
+            tokens.push(Token::LParen);
+            i += 1;
+        } else if c == ')' {
+            tokens.push(Token::RParen);
+            i += 1;
+        } else if c == '.' {
+            tokens.push(Token::Dot);
+            i += 1;
+        } else if c == '=' && chars.get(i + 1) == Some(&'=') {
+            tokens.push(Token::Op(CompareOp::Eq));
+            i += 2;
+        } else if c == '!' && chars.get(i + 1) == Some(&'=') {
+            tokens.push(Token::Op(CompareOp::Ne));
+            i += 2;
+        } else if c == '>' && chars.get(i + 1) == Some(&'=') {
+            tokens.push(Token::Op(CompareOp::Ge));
+            i += 2;
+        } else if c == '<' && chars.get(i + 1) == Some(&'=') {
+            tokens.push(Token::Op(CompareOp::Le));
+            i += 2;
+        } else if c == '<' {
+            tokens.push(Token::Op(CompareOp::Lt));
+            i += 1;
+        } else if c == '>' {
+            tokens.push(Token::Op(CompareOp::Gt));
+            i += 1;
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Int(text.parse()?));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Ident(text));
+        } else {
+            return Err(anyhow!("unexpected character '{}' in query", c));
+        }
+    }
+
+    Ok(tokens)
+}
+
+// --- Recursive-descent parser -----------------------------------------------
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Option<&Token> {
+        let tok = self.tokens.get(self.pos);
+        self.pos += 1;
+        tok
+    }
+
+    fn is_keyword(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(s)) if s.eq_ignore_ascii_case(word))
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr> {
+        self.parse_or()
+    }
+
+    fn parse_or(&mut self) -> Result<Expr> {
+        let mut left = self.parse_and()?;
+        while self.is_keyword("or") {
+            self.bump();
+            let right = self.parse_and()?;
+            left = Expr::Or(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr> {
+        let mut left = self.parse_unary()?;
+        while self.is_keyword("and") {
+            self.bump();
+            let right = self.parse_unary()?;
+            left = Expr::And(Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr> {
+        if self.is_keyword("not") {
+            self.bump();
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        if matches!(self.peek(), Some(Token::LParen)) {
+            self.bump();
+            let inner = self.parse_expr()?;
+            match self.bump() {
+                Some(Token::RParen) => Ok(inner),
+                _ => Err(anyhow!("expected closing ')'")),
+            }
+        } else {
+            self.parse_compare()
+        }
+    }
+
+    fn parse_compare(&mut self) -> Result<Expr> {
+        let field = self.parse_field()?;
+        let op = match self.bump() {
+            Some(Token::Op(op)) => *op,
+            other => return Err(anyhow!("expected comparison operator, found {:?}", other)),
+        };
+        let literal = self.parse_literal()?;
+        Ok(Expr::Compare(field, op, literal))
+    }
+
+    fn parse_field(&mut self) -> Result<Field> {
+        let name = match self.bump() {
+            Some(Token::Ident(s)) => s.clone(),
+            other => return Err(anyhow!("expected field name, found {:?}", other)),
+        };
+
+        let suit = match name.to_ascii_lowercase().as_str() {
+            "spades" => Some(SuitName::Spades),
+            "hearts" => Some(SuitName::Hearts),
+            "diamonds" => Some(SuitName::Diamonds),
+            "clubs" => Some(SuitName::Clubs),
+            _ => None,
+        };
+
+        if name.eq_ignore_ascii_case("hcp") || suit.is_some() {
+            if !matches!(self.bump(), Some(Token::LParen)) {
+                return Err(anyhow!("expected '(' after '{}'", name));
+            }
+            let dir_name = match self.bump() {
+                Some(Token::Ident(s)) => s.clone(),
+                other => return Err(anyhow!("expected seat name, found {:?}", other)),
+            };
+            let dir = parse_direction(&dir_name)?;
+            if !matches!(self.bump(), Some(Token::RParen)) {
+                return Err(anyhow!("expected ')' after seat name"));
+            }
+
+            return match suit {
+                Some(s) => {
+                    if !matches!(self.bump(), Some(Token::Dot)) {
+                        return Err(anyhow!("expected '.len' after '{}(...)'", name));
+                    }
+                    match self.bump() {
+                        Some(Token::Ident(s2)) if s2.eq_ignore_ascii_case("len") => {
+                            Ok(Field::SuitLen(dir, s))
+                        }
+                        other => Err(anyhow!("expected 'len', found {:?}", other)),
+                    }
+                }
+                None => Ok(Field::Hcp(dir)),
+            };
+        }
+
+        match name.to_ascii_lowercase().as_str() {
+            "vul" => Ok(Field::Vul),
+            "dealer" => Ok(Field::Dealer),
+            "contract" => {
+                if !matches!(self.bump(), Some(Token::Dot)) {
+                    return Err(anyhow!("expected '.strain' after 'contract'"));
+                }
+                match self.bump() {
+                    Some(Token::Ident(s)) if s.eq_ignore_ascii_case("strain") => {
+                        Ok(Field::ContractStrain)
+                    }
+                    other => Err(anyhow!("expected 'strain', found {:?}", other)),
+                }
+            }
+            other => Err(anyhow!("unknown field '{}'", other)),
+        }
+    }
+
+    fn parse_literal(&mut self) -> Result<Literal> {
+        match self.bump() {
+            Some(Token::Int(n)) => Ok(Literal::Int(*n)),
+            Some(Token::Ident(s)) => Ok(Literal::Ident(s.clone())),
+            other => Err(anyhow!("expected literal, found {:?}", other)),
+        }
+    }
+}
+
+fn parse_direction(name: &str) -> Result<Direction> {
+    match name.to_ascii_lowercase().as_str() {
+        "north" | "n" => Ok(Direction::North),
+        "east" | "e" => Ok(Direction::East),
+        "south" | "s" => Ok(Direction::South),
+        "west" | "w" => Ok(Direction::West),
+        other => Err(anyhow!("unknown seat '{}'", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_compare() {
+        let q = Query::parse("hcp(south) >= 25").unwrap();
+        match q.expr {
+            Expr::Compare(Field::Hcp(Direction::South), CompareOp::Ge, Literal::Int(25)) => {}
+            other => panic!("unexpected expr: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_and_or_not() {
+        let q = Query::parse("hcp(north) >= 12 and not (vul == ns or dealer == south)").unwrap();
+        assert!(matches!(q.expr, Expr::And(_, _)));
+    }
+
+    #[test]
+    fn test_parse_suit_len() {
+        let q = Query::parse("spades(north).len >= 6").unwrap();
+        match q.expr {
+            Expr::Compare(Field::SuitLen(Direction::North, SuitName::Spades), CompareOp::Ge, Literal::Int(6)) => {}
+            other => panic!("unexpected expr: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_contract_strain() {
+        let q = Query::parse("contract.strain == NT").unwrap();
+        match q.expr {
+            Expr::Compare(Field::ContractStrain, CompareOp::Eq, Literal::Ident(ref s)) => {
+                assert_eq!(s, "NT");
+            }
+            other => panic!("unexpected expr: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_strain_matches() {
+        assert!(strain_matches('N', "NT"));
+        assert!(strain_matches('S', "S"));
+        assert!(!strain_matches('S', "H"));
+    }
+
+    #[test]
+    fn test_vul_matches() {
+        assert!(vul_matches(Vulnerability::Both, "all"));
+        assert!(vul_matches(Vulnerability::NorthSouth, "ns"));
+        assert!(!vul_matches(Vulnerability::None, "ns"));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::pbn_document::PbnDocument;
+use anyhow::{anyhow, Context as _, Result};
+use clap::Args as ClapArgs;
+use std::path::PathBuf;
+
+mod rules;
+use rules::{
+    apply_fixes, lint, summarize, MandatoryTagsRule, Rule, StandardDealerRule,
+    StandardVulnerableRule,
+};
+
+#[derive(ClapArgs)]
+pub struct Args {
+    /// Input PBN file
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Apply available fixes and rewrite the file
+    #[arg(long)]
+    pub fix: bool,
+
+    /// Write fixed output to a different file instead of overwriting the input
+    /// (only used with --fix)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+fn default_rules() -> Vec<Box<dyn Rule>> {
+    vec![Box::new(StandardDealerRule), Box::new(StandardVulnerableRule), Box::new(MandatoryTagsRule)]
+}
+
+pub fn run(args: Args) -> Result<()> {
+    let content = std::fs::read_to_string(&args.input)
+        .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
+    let mut document = PbnDocument::parse(&content);
+    let rules = default_rules();
+
+    let mut diagnostics = lint(&document, &rules);
+    print_diagnostics(&diagnostics);
+    let (mut errors, mut warnings) = summarize(&diagnostics);
+
+    if args.fix {
+        let applied = apply_fixes(&mut document, &diagnostics);
+        if applied > 0 {
+            let output_path = args.output.unwrap_or_else(|| args.input.clone());
+            std::fs::write(&output_path, document.serialize()).with_context(|| {
+                format!("Failed to write output file: {}", output_path.display())
+            })?;
+            println!("Applied {} fix(es), wrote {}", applied, output_path.display());
+
+            diagnostics = lint(&document, &rules);
+            let counts = summarize(&diagnostics);
+            errors = counts.0;
+            warnings = counts.1;
+        }
+    }
+
+    println!("{} errors, {} warnings", errors, warnings);
+    if errors > 0 {
+        return Err(anyhow!("{} errors found", errors));
+    }
+    Ok(())
+}
+
+fn print_diagnostics(diagnostics: &[rules::Diagnostic]) {
+    for diagnostic in diagnostics {
+        match &diagnostic.tag {
+            Some(tag) => println!(
+                "board {}: {}: {} ({})",
+                diagnostic.board_index + 1,
+                diagnostic.severity,
+                diagnostic.message,
+                tag
+            ),
+            None => println!(
+                "board {}: {}: {}",
+                diagnostic.board_index + 1,
+                diagnostic.severity,
+                diagnostic.message
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules_flag_a_non_standard_dealer() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"1\"]\n[Dealer \"S\"]\n[Vulnerable \"None\"]\n[Deal \"N:...\"]\n",
+        );
+        let diagnostics = lint(&document, &default_rules());
+        assert!(diagnostics.iter().any(|d| d.tag.as_deref() == Some("Dealer")));
+    }
+
+    #[test]
+    fn test_well_formed_standard_board_has_no_diagnostics() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"1\"]\n[Dealer \"N\"]\n[Vulnerable \"None\"]\n[Deal \"N:...\"]\n",
+        );
+        let diagnostics = lint(&document, &default_rules());
+        assert!(diagnostics.is_empty());
+    }
+}
@@ -0,0 +1,333 @@
+//! The rule engine behind `wrangle lint`: a `Rule` checks one board at a
+//! time (so the runner can check boards in parallel for large files) and
+//! writes `Diagnostic`s into a per-board `Context`; board-numbering is
+//! checked separately since it needs the whole document at once.
+
+use crate::commands::block_replicate::{STANDARD_DEALER, STANDARD_VUL};
+use crate::pbn_document::{PbnBoard, PbnDocument};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+
+/// How serious a `Diagnostic` is. An `Error` fails the lint run; `Warning`
+/// and `Info` are reported but don't affect the exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// A single-tag rewrite that `--fix` can apply to resolve a `Diagnostic`.
+#[derive(Debug, Clone)]
+pub struct Fix {
+    pub tag: String,
+    pub new_value: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub board_index: usize,
+    pub tag: Option<String>,
+    pub fix: Option<Fix>,
+}
+
+/// Per-board scratch state a `Rule` writes its findings into.
+pub struct Context {
+    board_index: usize,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Context {
+    fn new(board_index: usize) -> Self {
+        Context { board_index, diagnostics: Vec::new() }
+    }
+
+    /// Record a diagnostic against the board this context was built for.
+    pub fn emit(
+        &mut self,
+        severity: Severity,
+        message: impl Into<String>,
+        tag: Option<&str>,
+        fix: Option<Fix>,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            message: message.into(),
+            board_index: self.board_index,
+            tag: tag.map(|t| t.to_string()),
+            fix,
+        });
+    }
+}
+
+/// A single lint check over one board.
+pub trait Rule: Send + Sync {
+    fn check(&self, board: &PbnBoard, ctx: &mut Context);
+}
+
+fn board_number(board: &PbnBoard) -> Option<u32> {
+    board.tag("Board")?.parse().ok()
+}
+
+/// Board `n`'s Dealer tag should match the repeating 4-board standard
+/// rotation. Not autofixable: a mismatch is as likely to mean the board was
+/// dealt intentionally off-rotation as it is to be an error.
+pub struct StandardDealerRule;
+
+impl Rule for StandardDealerRule {
+    fn check(&self, board: &PbnBoard, ctx: &mut Context) {
+        let (Some(board_num), Some(dealer_value)) = (board_number(board), board.tag("Dealer")) else {
+            return;
+        };
+        let expected = STANDARD_DEALER[((board_num - 1) % 4) as usize].to_char();
+        if dealer_value != expected.to_string() {
+            ctx.emit(
+                Severity::Warning,
+                format!(
+                    "board {} dealer is \"{}\", expected \"{}\" for the standard rotation",
+                    board_num, dealer_value, expected
+                ),
+                Some("Dealer"),
+                None,
+            );
+        }
+    }
+}
+
+/// Board `n`'s Vulnerable tag should match the repeating 16-board standard
+/// pattern. Autofixable, since the standard value is unambiguous.
+pub struct StandardVulnerableRule;
+
+impl Rule for StandardVulnerableRule {
+    fn check(&self, board: &PbnBoard, ctx: &mut Context) {
+        let (Some(board_num), Some(vul_value)) = (board_number(board), board.tag("Vulnerable"))
+        else {
+            return;
+        };
+        let expected = STANDARD_VUL[((board_num - 1) % 16) as usize].to_pbn();
+        if vul_value != expected {
+            ctx.emit(
+                Severity::Warning,
+                format!(
+                    "board {} vulnerability is \"{}\", expected \"{}\" for the standard rotation",
+                    board_num, vul_value, expected
+                ),
+                Some("Vulnerable"),
+                Some(Fix { tag: "Vulnerable".to_string(), new_value: expected.to_string() }),
+            );
+        }
+    }
+}
+
+/// The mandatory tags every board needs, in PBN's conventional order.
+const MANDATORY_TAGS: [&str; 5] = ["Event", "Board", "Dealer", "Vulnerable", "Deal"];
+
+/// Every mandatory tag must be present, in the standard order.
+pub struct MandatoryTagsRule;
+
+impl Rule for MandatoryTagsRule {
+    fn check(&self, board: &PbnBoard, ctx: &mut Context) {
+        let present: Vec<&str> = board
+            .tags
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| MANDATORY_TAGS.contains(name))
+            .collect();
+
+        for tag in MANDATORY_TAGS {
+            if !present.contains(&tag) {
+                ctx.emit(
+                    Severity::Error,
+                    format!("board is missing mandatory tag \"{}\"", tag),
+                    Some(tag),
+                    None,
+                );
+            }
+        }
+
+        let expected: Vec<&str> =
+            MANDATORY_TAGS.iter().copied().filter(|tag| present.contains(tag)).collect();
+        if present != expected {
+            ctx.emit(
+                Severity::Error,
+                format!(
+                    "mandatory tags are out of order: found {:?}, expected {:?}",
+                    present, expected
+                ),
+                None,
+                None,
+            );
+        }
+    }
+}
+
+/// Boards worth checking in parallel rather than sequentially; below this,
+/// spinning up rayon's thread pool costs more than it saves.
+const PARALLEL_THRESHOLD: usize = 64;
+
+/// Run every per-board rule, plus the whole-document board-numbering check,
+/// returning diagnostics in board order.
+pub fn lint(document: &PbnDocument, rules: &[Box<dyn Rule>]) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> = if document.boards.len() >= PARALLEL_THRESHOLD {
+        document
+            .boards
+            .par_iter()
+            .enumerate()
+            .flat_map(|(index, board)| lint_board(board, index, rules))
+            .collect()
+    } else {
+        document
+            .boards
+            .iter()
+            .enumerate()
+            .flat_map(|(index, board)| lint_board(board, index, rules))
+            .collect()
+    };
+
+    diagnostics.extend(check_board_numbers(document));
+    diagnostics.sort_by_key(|d| d.board_index);
+    diagnostics
+}
+
+fn lint_board(board: &PbnBoard, index: usize, rules: &[Box<dyn Rule>]) -> Vec<Diagnostic> {
+    let mut ctx = Context::new(index);
+    for rule in rules {
+        rule.check(board, &mut ctx);
+    }
+    ctx.diagnostics
+}
+
+/// Board numbers must be unique and form a contiguous run; gaps or repeats
+/// usually mean boards were deleted, reordered, or duplicated by hand.
+fn check_board_numbers(document: &PbnDocument) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut first_seen_at: HashMap<u32, usize> = HashMap::new();
+
+    for (index, board) in document.boards.iter().enumerate() {
+        let Some(board_num) = board_number(board) else { continue };
+        if let Some(&first_index) = first_seen_at.get(&board_num) {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Error,
+                message: format!(
+                    "board number {} is duplicated (first seen at board index {})",
+                    board_num, first_index
+                ),
+                board_index: index,
+                tag: Some("Board".to_string()),
+                fix: None,
+            });
+        } else {
+            first_seen_at.insert(board_num, index);
+        }
+    }
+
+    let mut numbers: Vec<u32> = first_seen_at.keys().copied().collect();
+    numbers.sort_unstable();
+    for pair in numbers.windows(2) {
+        if pair[1] != pair[0] + 1 {
+            diagnostics.push(Diagnostic {
+                severity: Severity::Error,
+                message: format!(
+                    "board numbers are not contiguous: {} is followed by {}",
+                    pair[0], pair[1]
+                ),
+                board_index: first_seen_at[&pair[1]],
+                tag: Some("Board".to_string()),
+                fix: None,
+            });
+        }
+    }
+
+    diagnostics
+}
+
+/// Apply every diagnostic's fix (if any) to the matching board, returning
+/// the number of fixes applied.
+pub fn apply_fixes(document: &mut PbnDocument, diagnostics: &[Diagnostic]) -> usize {
+    let mut applied = 0;
+    for diagnostic in diagnostics {
+        if let Some(fix) = &diagnostic.fix {
+            if let Some(board) = document.boards.get_mut(diagnostic.board_index) {
+                board.set_tag(&fix.tag, fix.new_value.clone());
+                applied += 1;
+            }
+        }
+    }
+    applied
+}
+
+/// Count of (errors, warnings) among `diagnostics`.
+pub fn summarize(diagnostics: &[Diagnostic]) -> (usize, usize) {
+    let errors = diagnostics.iter().filter(|d| d.severity == Severity::Error).count();
+    let warnings = diagnostics.iter().filter(|d| d.severity == Severity::Warning).count();
+    (errors, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn default_rules() -> Vec<Box<dyn Rule>> {
+        vec![Box::new(StandardDealerRule), Box::new(StandardVulnerableRule), Box::new(MandatoryTagsRule)]
+    }
+
+    #[test]
+    fn test_standard_dealer_rule_flags_mismatch() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"1\"]\n[Dealer \"E\"]\n[Vulnerable \"None\"]\n[Deal \"N:...\"]\n",
+        );
+        let diagnostics = lint(&document, &default_rules());
+        assert!(diagnostics.iter().any(|d| d.tag.as_deref() == Some("Dealer")));
+    }
+
+    #[test]
+    fn test_standard_vulnerable_rule_is_autofixable() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"2\"]\n[Dealer \"E\"]\n[Vulnerable \"None\"]\n[Deal \"N:...\"]\n",
+        );
+        let diagnostics = lint(&document, &default_rules());
+        let vul_diagnostic = diagnostics.iter().find(|d| d.tag.as_deref() == Some("Vulnerable")).unwrap();
+        assert_eq!(vul_diagnostic.fix.as_ref().unwrap().new_value, "NS");
+    }
+
+    #[test]
+    fn test_mandatory_tags_rule_flags_missing_tag() {
+        let document = PbnDocument::parse("[Board \"1\"]\n[Dealer \"N\"]\n");
+        let diagnostics = lint(&document, &default_rules());
+        assert!(diagnostics.iter().any(|d| d.message.contains("Event")));
+    }
+
+    #[test]
+    fn test_check_board_numbers_flags_duplicates_and_gaps() {
+        let document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"1\"]\n\n[Event \"Club\"]\n[Board \"1\"]\n\n[Event \"Club\"]\n[Board \"3\"]\n",
+        );
+        let diagnostics = check_board_numbers(&document);
+        assert!(diagnostics.iter().any(|d| d.message.contains("duplicated")));
+        assert!(diagnostics.iter().any(|d| d.message.contains("not contiguous")));
+    }
+
+    #[test]
+    fn test_apply_fixes_rewrites_tagged_value() {
+        let mut document = PbnDocument::parse(
+            "[Event \"Club\"]\n[Board \"2\"]\n[Dealer \"E\"]\n[Vulnerable \"None\"]\n[Deal \"N:...\"]\n",
+        );
+        let diagnostics = lint(&document, &default_rules());
+        let applied = apply_fixes(&mut document, &diagnostics);
+        assert_eq!(applied, 1);
+        assert_eq!(document.boards[0].tag("Vulnerable"), Some("NS"));
+    }
+}
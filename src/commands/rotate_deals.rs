@@ -1,11 +1,15 @@
 use anyhow::{anyhow, Context, Result};
-use bridge_parsers::model::{Board, Direction, Vulnerability};
+use bridge_parsers::model::{Board, Direction, Hand, Holding, Rank, Vulnerability};
 use bridge_parsers::pbn::read_pbn;
 use clap::{Args as ClapArgs, ValueEnum};
 use regex::Regex;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+mod validate;
+
+use validate::{describe_errors, validate_deal};
+
 #[derive(Debug, Clone, Copy, ValueEnum, Default)]
 pub enum RotationBasis {
     /// Standard basis: RotationBasis tag, Student, Declarer, Dealer, Deal (in priority order)
@@ -31,6 +35,17 @@ pub enum RotationBasis {
     West,
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum, Default)]
+pub enum LineEndings {
+    /// Match the dominant line ending found in the input file
+    #[default]
+    Auto,
+    /// Force Unix-style LF (\n) line endings
+    Lf,
+    /// Force Windows-style CRLF (\r\n) line endings
+    Crlf,
+}
+
 #[derive(ClapArgs)]
 pub struct Args {
     /// Input PBN file
@@ -54,6 +69,18 @@ pub struct Args {
     /// Use standard vulnerability based on board number instead of rotating
     #[arg(long)]
     pub standard_vul: bool,
+
+    /// Abort if any deal is invalid instead of skipping those boards and warning
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Print a terminal hand-diagram preview of the rotated boards instead of writing output files
+    #[arg(long)]
+    pub preview: bool,
+
+    /// Line ending style for the output file(s)
+    #[arg(long, value_enum, default_value = "auto")]
+    pub line_endings: LineEndings,
 }
 
 /// Information about the rotation applied to a board
@@ -106,6 +133,15 @@ pub fn run(args: Args) -> Result<()> {
     let content = std::fs::read_to_string(&args.input)
         .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
 
+    // Detect (or honor the forced choice of) line ending style, and whether
+    // the input ends with a trailing newline, so the output matches
+    let line_ending = match args.line_endings {
+        LineEndings::Auto => detect_line_ending(&content),
+        LineEndings::Lf => "\n",
+        LineEndings::Crlf => "\r\n",
+    };
+    let trailing_newline = content.ends_with('\n');
+
     // Parse extra tags that bridge-parsers doesn't handle
     let extra_tags = parse_extra_tags(&content);
 
@@ -134,6 +170,9 @@ pub fn run(args: Args) -> Result<()> {
         // Track rotation info per board
         let mut rotation_infos: HashMap<u32, RotationInfo> = HashMap::new();
 
+        // Deal-validation messages per board, reported once rotation is done
+        let mut validation_warnings: Vec<(u32, String)> = Vec::new();
+
         // Rotate each board
         for (i, board) in rotated_boards.iter_mut().enumerate() {
             // Assign board number if missing
@@ -161,9 +200,55 @@ pub fn run(args: Args) -> Result<()> {
                 use_standard_vul: args.standard_vul,
             });
 
+            let pre_rotation_errors = validate_deal(board);
+
             if rotation != 0 {
                 rotate_board(board, rotation, args.standard_vul);
             }
+
+            match (&pre_rotation_errors, validate_deal(board)) {
+                (Ok(()), Err(post_errors)) => {
+                    // The board was legal before we touched it, so a newly
+                    // invalid deal means the rotation itself has a bug.
+                    validation_warnings.push((
+                        board_num,
+                        format!("rotation introduced an invalid deal: {}", describe_errors(&post_errors)),
+                    ));
+                }
+                (Err(pre_errors), _) => {
+                    validation_warnings.push((board_num, describe_errors(pre_errors)));
+                }
+                (Ok(()), Ok(())) => {}
+            }
+        }
+
+        if !validation_warnings.is_empty() {
+            let summary = validation_warnings
+                .iter()
+                .map(|(num, msg)| format!("Board {}: {}", num, msg))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            if args.strict {
+                return Err(anyhow!("Deal validation failed:\n{}", summary));
+            }
+
+            eprintln!("Warning: deal validation issues found, skipping these boards:\n{}", summary);
+            let invalid: Vec<u32> = validation_warnings.iter().map(|(num, _)| *num).collect();
+            rotated_boards.retain(|b| !b.number.map(|n| invalid.contains(&n)).unwrap_or(false));
+        }
+
+        if args.preview {
+            for board in &rotated_boards {
+                let board_num = board.number.unwrap_or(0);
+                print!("{}", render_board_preview(board, rotation_infos.get(&board_num)));
+            }
+            println!(
+                "Preview only: {} boards for pattern {} (no files written)",
+                rotated_boards.len(),
+                pattern_str
+            );
+            continue;
         }
 
         // Determine output path
@@ -174,7 +259,14 @@ pub fn run(args: Args) -> Result<()> {
         };
 
         // Write output using our custom writer that handles extra tags
-        let output_content = write_rotated_pbn(&content, &extra_tags, &rotated_boards, &rotation_infos)?;
+        let output_content = write_rotated_pbn(
+            &content,
+            &extra_tags,
+            &rotated_boards,
+            &rotation_infos,
+            line_ending,
+            trailing_newline,
+        )?;
         std::fs::write(&output_path, output_content)
             .with_context(|| format!("Failed to write output file: {}", output_path.display()))?;
 
@@ -293,11 +385,7 @@ fn find_basis(
         RotationBasis::Student => (get_tag_direction("Student").unwrap_or(Direction::North), "Student"),
         RotationBasis::Declarer => (get_tag_direction("Declarer").unwrap_or(Direction::North), "Declarer"),
         RotationBasis::Dealer => (board.dealer.unwrap_or(Direction::North), "Dealer"),
-        RotationBasis::Deal => {
-            // The deal's first character indicates which hand is listed first
-            // For now, use dealer as fallback
-            (board.dealer.unwrap_or(Direction::North), "Deal")
-        }
+        RotationBasis::Deal => (deal_starting_seat(board, tags).unwrap_or(Direction::North), "Deal"),
         RotationBasis::North => (Direction::North, "North"),
         RotationBasis::South => (Direction::South, "South"),
         RotationBasis::East => (Direction::East, "East"),
@@ -305,6 +393,27 @@ fn find_basis(
     }
 }
 
+/// Read the seat prefix off a PBN `[Deal "X:..."]` value, e.g. the `X` in
+/// "N:AKQ... ...". Falls back to re-serializing `board.deal` (using the
+/// board's dealer as the starting seat) when the raw tag wasn't captured in
+/// `tags`, and to `None` if neither yields a parseable prefix.
+fn deal_starting_seat(board: &Board, tags: Option<&HashMap<String, String>>) -> Option<Direction> {
+    if let Some(prefix) = tags.and_then(|t| t.get("Deal")) {
+        if let Some(dir) = prefix.split(':').next().and_then(|s| s.chars().next()).and_then(Direction::from_char) {
+            return Some(dir);
+        }
+    }
+
+    let fallback_dir = board.dealer.unwrap_or(Direction::North);
+    board
+        .deal
+        .to_pbn(fallback_dir)
+        .split(':')
+        .next()
+        .and_then(|s| s.chars().next())
+        .and_then(Direction::from_char)
+}
+
 /// Calculate how many positions to rotate clockwise (0-3)
 fn rotation_amount(from: Direction, to: Direction) -> u8 {
     let from_idx = direction_index(from);
@@ -321,9 +430,13 @@ fn direction_index(dir: Direction) -> usize {
     }
 }
 
-fn rotate_direction(dir: Direction, rotation: u8) -> Direction {
-    let idx = direction_index(dir);
-    let new_idx = (idx + rotation as usize) % 4;
+/// Rotate `dir` by `rotation` quarter-turns clockwise. `rotation` may be
+/// negative (counter-clockwise) or outside `0..4`; it's normalized with
+/// Euclidean remainder before dispatching, so `rotate_direction(North, -1)`
+/// is West and `rotate_direction(North, 7)` is the same as `rotate_direction(North, 3)`.
+fn rotate_direction(dir: Direction, rotation: i32) -> Direction {
+    let idx = direction_index(dir) as i32;
+    let new_idx = (idx + rotation).rem_euclid(4);
     match new_idx {
         0 => Direction::North,
         1 => Direction::East,
@@ -333,11 +446,79 @@ fn rotate_direction(dir: Direction, rotation: u8) -> Direction {
     }
 }
 
-/// Rotate a board by the given amount (0-3 positions clockwise)
+/// Seat-relationship navigation for `Direction`, so callers can write
+/// `seat.partner()` instead of sprinkling `rotate_direction(seat, 2)` for
+/// "partner" throughout the deal-manipulation code.
+trait DirectionExt {
+    /// One seat clockwise: N -> E -> S -> W -> N.
+    fn next(self) -> Direction;
+    /// One seat counter-clockwise: N -> W -> S -> E -> N.
+    fn prev(self) -> Direction;
+    /// The seat across the table.
+    fn partner(self) -> Direction;
+    /// Left-hand opponent (the seat that plays after this one).
+    fn lho(self) -> Direction;
+    /// Right-hand opponent (the seat that plays before this one).
+    fn rho(self) -> Direction;
+    /// Both opponents, in `[lho, rho]` order.
+    fn opponents(self) -> [Direction; 2];
+    /// Whether this seat is on the North-South side.
+    fn is_ns(self) -> bool;
+    /// Whether this seat is on the East-West side.
+    fn is_ew(self) -> bool;
+}
+
+impl DirectionExt for Direction {
+    fn next(self) -> Direction {
+        rotate_direction(self, 1)
+    }
+
+    fn prev(self) -> Direction {
+        rotate_direction(self, -1)
+    }
+
+    fn partner(self) -> Direction {
+        rotate_direction(self, 2)
+    }
+
+    fn lho(self) -> Direction {
+        self.next()
+    }
+
+    fn rho(self) -> Direction {
+        self.prev()
+    }
+
+    fn opponents(self) -> [Direction; 2] {
+        [self.lho(), self.rho()]
+    }
+
+    fn is_ns(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    fn is_ew(self) -> bool {
+        matches!(self, Direction::East | Direction::West)
+    }
+}
+
+/// Rotate every seat-relative field of a board by `rotation` quarter-turns
+/// clockwise: the four hands (reassigning which seat holds which cards), the
+/// dealer, and the vulnerability marker. This is the cross-cutting piece
+/// that lets a caller normalize a board's orientation in one call, e.g. so
+/// the declarer is always shown as South.
+///
+/// `bridge_parsers::Board` only carries the deal, dealer and vulnerability,
+/// so that's everything this function touches. The Auction, Play, Declarer
+/// and Score tags exist only as raw PBN text at this layer (not as fields on
+/// `Board`), and are kept in lockstep by the tag-rewriting pass in
+/// `write_rotated_pbn`, via `rotate_direction_value` and `rotate_score_value`.
 fn rotate_board(board: &mut Board, rotation: u8, use_standard_vul: bool) {
+    let original_dealer = board.dealer;
+
     // Rotate dealer
     if let Some(dealer) = board.dealer {
-        board.dealer = Some(rotate_direction(dealer, rotation));
+        board.dealer = Some(rotate_direction(dealer, rotation as i32));
     }
 
     // Rotate vulnerability
@@ -346,8 +527,15 @@ fn rotate_board(board: &mut Board, rotation: u8, use_standard_vul: bool) {
             board.vulnerable = Vulnerability::from_board_number(num);
         }
     } else {
-        // For odd rotations, swap NS and EW vulnerability
-        if rotation % 2 == 1 {
+        // NS/EW vulnerability swaps exactly when the rotation carries a seat
+        // across the NS/EW axis (e.g. North, on the NS side, is rotated to
+        // sit East). Asking that question of a reference seat via
+        // `is_ns()`/partner-style navigation is more self-documenting than a
+        // bare `rotation % 2 == 1` parity check, and it's equivalent for any
+        // reference seat, so fall back to North when there's no dealer.
+        let reference = original_dealer.unwrap_or(Direction::North);
+        let crossed_axis = reference.is_ns() != rotate_direction(reference, rotation as i32).is_ns();
+        if crossed_axis {
             board.vulnerable = match board.vulnerable {
                 Vulnerability::NorthSouth => Vulnerability::EastWest,
                 Vulnerability::EastWest => Vulnerability::NorthSouth,
@@ -359,16 +547,90 @@ fn rotate_board(board: &mut Board, rotation: u8, use_standard_vul: bool) {
     // Rotate the deal (swap hands around the table)
     let old_deal = board.deal.clone();
     for dir in Direction::all() {
-        let source_dir = rotate_direction(dir, 4 - rotation);
+        let source_dir = rotate_direction(dir, -(rotation as i32));
         let hand = old_deal.hand(source_dir).clone();
         board.deal.set_hand(dir, hand);
     }
 }
 
+/// Render a compass-layout hand diagram for a single rotated board: North on
+/// top, West/East on the sides, South on the bottom, suits ordered
+/// ♠♥♦♣ with ranks sorted high to low.
+fn render_board_preview(board: &Board, info: Option<&RotationInfo>) -> String {
+    let board_num = board.number.unwrap_or(0);
+    let dealer = board.dealer.map(|d| d.to_char()).unwrap_or('?');
+    let vul = board.vulnerable.to_pbn();
+    let rotation_note = info
+        .map(|i| format!("  [rotated {} -> {}, {} positions]", i.basis.to_char(), i.target.to_char(), i.rotation))
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "Board {}  Dealer: {}  Vul: {}{}\n",
+        board_num, dealer, vul, rotation_note
+    ));
+
+    let north = hand_lines(board.deal.hand(Direction::North));
+    let south = hand_lines(board.deal.hand(Direction::South));
+    let west = hand_lines(board.deal.hand(Direction::West));
+    let east = hand_lines(board.deal.hand(Direction::East));
+
+    for line in &north {
+        out.push_str(&format!("{:^40}\n", line));
+    }
+    for i in 0..4 {
+        out.push_str(&format!("{:<20}{:>20}\n", west[i], east[i]));
+    }
+    for line in &south {
+        out.push_str(&format!("{:^40}\n", line));
+    }
+    out.push('\n');
+    out
+}
+
+/// One line per suit, in ♠♥♦♣ order, ranks sorted high to low.
+fn hand_lines(hand: &Hand) -> [String; 4] {
+    [
+        suit_line('\u{2660}', &hand.spades),
+        suit_line('\u{2665}', &hand.hearts),
+        suit_line('\u{2666}', &hand.diamonds),
+        suit_line('\u{2663}', &hand.clubs),
+    ]
+}
+
+fn suit_line(symbol: char, holding: &Holding) -> String {
+    let mut ranks: Vec<Rank> = holding.ranks.clone();
+    ranks.sort_by_key(|r| std::cmp::Reverse(rank_value(*r)));
+    let rank_str: String = ranks.iter().map(|r| r.to_char()).collect();
+    if rank_str.is_empty() {
+        format!("{} -", symbol)
+    } else {
+        format!("{} {}", symbol, rank_str)
+    }
+}
+
+fn rank_value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 14,
+        Rank::King => 13,
+        Rank::Queen => 12,
+        Rank::Jack => 11,
+        Rank::Ten => 10,
+        Rank::Nine => 9,
+        Rank::Eight => 8,
+        Rank::Seven => 7,
+        Rank::Six => 6,
+        Rank::Five => 5,
+        Rank::Four => 4,
+        Rank::Three => 3,
+        Rank::Two => 2,
+    }
+}
+
 /// Rotate a direction character (N, E, S, W)
 fn rotate_direction_char(c: char, rotation: u8) -> char {
     if let Some(dir) = Direction::from_char(c) {
-        let rotated = rotate_direction(dir, rotation);
+        let rotated = rotate_direction(dir, rotation as i32);
         if c.is_uppercase() {
             rotated.to_char()
         } else {
@@ -389,9 +651,11 @@ fn rotate_direction_value(value: &str, rotation: u8) -> String {
     }
 }
 
-/// Rotate a Score tag value (e.g., "NS 420" -> "EW 420" for odd rotations)
-fn rotate_score_value(value: &str, rotation: u8) -> String {
-    if rotation % 2 == 0 {
+/// Rotate a Score tag value (e.g., "NS 420" -> "EW 420" for odd rotations).
+/// `rotation` may be negative or outside `0..4`; it's normalized the same
+/// way `rotate_direction` is.
+fn rotate_score_value(value: &str, rotation: i32) -> String {
+    if rotation.rem_euclid(4) % 2 == 0 {
         return value.to_string();
     }
 
@@ -404,54 +668,129 @@ fn rotate_score_value(value: &str, rotation: u8) -> String {
     }
 }
 
-/// Rotate direction words in commentary text
-fn rotate_commentary(text: &str, rotation: u8) -> String {
-    if rotation == 0 {
+/// Rotate a vulnerability token to match a board turned `rotation`
+/// quarter-turns clockwise, mirroring the NS/EW swap `rotate_score_value`
+/// does for the paired side label. "None"/"Love" and "Both"/"All" are left
+/// as-is since neither singles out a side; "NS" and "EW" swap on odd
+/// rotations. `rotation` may be negative or outside `0..4`; it's normalized
+/// the same way `rotate_direction` is.
+fn rotate_vulnerability(value: &str, rotation: i32) -> String {
+    if rotation.rem_euclid(4) % 2 == 0 {
+        return value.to_string();
+    }
+
+    match value {
+        "NS" => "EW".to_string(),
+        "EW" => "NS".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Rotate direction words in commentary text: whole words ("North"/"south"),
+/// single-letter seat abbreviations standing alone ("N", "e"), and their
+/// possessive/plural forms ("North's", "Easts") are rewritten to the seat
+/// `rotation` quarter-turns clockwise away. The original casing of each
+/// matched token is preserved (ALL-CAPS stays ALL-CAPS, Title-case stays
+/// Title-case, lowercase stays lowercase) and everything else — punctuation,
+/// unrelated words, and words that merely contain a direction name like
+/// "Northampton" — is left untouched. `rotation` may be negative or outside
+/// `0..4`; it's normalized the same way `rotate_direction` is.
+fn rotate_commentary(text: &str, rotation: i32) -> String {
+    if rotation.rem_euclid(4) == 0 {
         return text.to_string();
     }
 
-    let directions = ["North", "East", "South", "West"];
+    let pattern = Regex::new(r"(?i)\b(north|east|south|west|[nesw])('s|s)?\b").unwrap();
 
-    // Create regex patterns with word boundaries for each direction (case-insensitive)
-    let patterns: Vec<Regex> = directions
-        .iter()
-        .map(|d| Regex::new(&format!(r"(?i)\b{}\b", d)).unwrap())
-        .collect();
+    // Built by hand (rather than `replace_all`) so a single-letter match can
+    // be skipped when it's really the tail of a contraction like "it's",
+    // which a plain `\b` can't tell apart from a standalone seat letter.
+    let mut result = String::with_capacity(text.len());
+    let mut last_end = 0;
+    for caps in pattern.captures_iter(text) {
+        let m = caps.get(0).unwrap();
+        let word = &caps[1];
+        let suffix = caps.get(2).map(|m| m.as_str()).unwrap_or("");
 
-    // Create temporary placeholders to avoid double-replacement
-    let mut result = text.to_string();
+        if word.len() == 1 && text[..m.start()].ends_with('\'') {
+            continue;
+        }
 
-    // First pass: replace with placeholders, preserving case
-    for (i, pattern) in patterns.iter().enumerate() {
-        result = pattern
-            .replace_all(&result, |caps: &regex::Captures| {
-                let matched = &caps[0];
-                if matched.chars().next().unwrap().is_uppercase() {
-                    if matched.chars().all(|c| c.is_uppercase()) {
-                        format!("__DIR_UPPER_{}__", i)
-                    } else {
-                        format!("__DIR_TITLE_{}__", i)
-                    }
-                } else {
-                    format!("__DIR_LOWER_{}__", i)
-                }
-            })
-            .to_string();
+        let dir = match word.to_ascii_lowercase().as_str() {
+            "north" | "n" => Direction::North,
+            "east" | "e" => Direction::East,
+            "south" | "s" => Direction::South,
+            "west" | "w" => Direction::West,
+            _ => unreachable!("regex only matches seat words and abbreviations"),
+        };
+        let rotated = rotate_direction(dir, rotation);
+
+        let new_word = if word.len() == 1 {
+            rotated.to_char().to_string()
+        } else {
+            direction_name(rotated).to_string()
+        };
+
+        result.push_str(&text[last_end..m.start()]);
+        result.push_str(&apply_word_case(&new_word, word_case(word)));
+        result.push_str(suffix);
+        last_end = m.end();
     }
+    result.push_str(&text[last_end..]);
+    result
+}
+
+/// How a matched token was capitalized, so the replacement can mirror it.
+#[derive(Clone, Copy)]
+enum WordCase {
+    Upper,
+    Title,
+    Lower,
+}
 
-    // Second pass: replace placeholders with rotated directions
-    for (i, _) in directions.iter().enumerate() {
-        let new_idx = (i + rotation as usize) % 4;
-        let new_title = directions[new_idx];
-        let new_lower = new_title.to_lowercase();
-        let new_upper = new_title.to_uppercase();
-        result = result
-            .replace(&format!("__DIR_TITLE_{}__", i), new_title)
-            .replace(&format!("__DIR_LOWER_{}__", i), &new_lower)
-            .replace(&format!("__DIR_UPPER_{}__", i), &new_upper);
+fn word_case(word: &str) -> WordCase {
+    if word.chars().all(|c| c.is_uppercase()) {
+        WordCase::Upper
+    } else if word.chars().next().map(|c| c.is_uppercase()).unwrap_or(false) {
+        WordCase::Title
+    } else {
+        WordCase::Lower
     }
+}
 
-    result
+fn apply_word_case(word: &str, case: WordCase) -> String {
+    match case {
+        WordCase::Upper => word.to_uppercase(),
+        WordCase::Lower => word.to_lowercase(),
+        WordCase::Title => {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+                None => String::new(),
+            }
+        }
+    }
+}
+
+fn direction_name(dir: Direction) -> &'static str {
+    match dir {
+        Direction::North => "North",
+        Direction::East => "East",
+        Direction::South => "South",
+        Direction::West => "West",
+    }
+}
+
+/// Detect the dominant line ending in `content`: CRLF if at least as many
+/// lines use "\r\n" as use a lone "\n", otherwise LF.
+fn detect_line_ending(content: &str) -> &'static str {
+    let crlf_count = content.matches("\r\n").count();
+    let lf_count = content.matches('\n').count().saturating_sub(crlf_count);
+    if crlf_count > 0 && crlf_count >= lf_count {
+        "\r\n"
+    } else {
+        "\n"
+    }
 }
 
 /// Write rotated PBN content, preserving original structure and rotating additional tags
@@ -460,6 +799,8 @@ fn write_rotated_pbn(
     _extra_tags: &HashMap<u32, HashMap<String, String>>,
     rotated_boards: &[Board],
     rotation_infos: &HashMap<u32, RotationInfo>,
+    line_ending: &str,
+    trailing_newline: bool,
 ) -> Result<String> {
     let mut output = String::new();
     let mut current_board_num: Option<u32> = None;
@@ -503,7 +844,7 @@ fn write_rotated_pbn(
                 in_commentary = false;
                 // Rotate commentary and output
                 if current_board_num.is_some() && current_rotation != 0 {
-                    let rotated = rotate_commentary(&commentary_buffer, current_rotation);
+                    let rotated = rotate_commentary(&commentary_buffer, current_rotation as i32);
                     output.push_str(&rotated);
                 } else {
                     output.push_str(&commentary_buffer);
@@ -515,7 +856,7 @@ fn write_rotated_pbn(
         // Handle single-line commentary
         if trimmed.starts_with('{') && trimmed.ends_with('}') {
             if current_board_num.is_some() && current_rotation != 0 {
-                let rotated = rotate_commentary(line, current_rotation);
+                let rotated = rotate_commentary(line, current_rotation as i32);
                 output.push_str(&rotated);
                 output.push('\n');
             } else {
@@ -600,7 +941,18 @@ fn write_rotated_pbn(
                         format!("[Dealer \"{}\"]", board.dealer.map(|d| d.to_char()).unwrap_or('N'))
                     }
                     "Vulnerable" => {
-                        format!("[Vulnerable \"{}\"]", board.vulnerable.to_pbn())
+                        // `--standard-vul` recomputes vulnerability from the board
+                        // number rather than rotating it, so `board.vulnerable`
+                        // (not the original tag text) is already authoritative for
+                        // that case; otherwise rotate the original tag text just
+                        // like the Score tag below.
+                        let info = rotation_infos.get(&current_board_num.unwrap());
+                        let value = if info.map(|i| i.use_standard_vul).unwrap_or(false) {
+                            board.vulnerable.to_pbn().to_string()
+                        } else {
+                            rotate_vulnerability(tag_value, rotation as i32)
+                        };
+                        format!("[Vulnerable \"{}\"]", value)
                     }
                     "Deal" => {
                         let first_dir = board.dealer.unwrap_or(Direction::North);
@@ -616,7 +968,7 @@ fn write_rotated_pbn(
                         format!("[Declarer \"{}\"]", rotated_value)
                     }
                     "Score" => {
-                        let rotated_value = rotate_score_value(tag_value, rotation);
+                        let rotated_value = rotate_score_value(tag_value, rotation as i32);
                         format!("[Score \"{}\"]", rotated_value)
                     }
                     "BCFlags" => {
@@ -645,6 +997,19 @@ fn write_rotated_pbn(
         }
     }
 
+    // The body above is built with plain LF terminators throughout (original
+    // lines have any "\r" stripped by `.lines()`); convert once here rather
+    // than threading the terminator through every push site.
+    let mut output = if line_ending == "\r\n" {
+        output.replace('\n', "\r\n")
+    } else {
+        output
+    };
+
+    if !trailing_newline && output.ends_with(line_ending) {
+        output.truncate(output.len() - line_ending.len());
+    }
+
     Ok(output)
 }
 
@@ -678,6 +1043,21 @@ mod tests {
         assert_eq!(rotation_amount(Direction::South, Direction::North), 2);
     }
 
+    #[test]
+    fn test_deal_starting_seat_from_tag() {
+        let board = Board::default();
+        let mut tags = HashMap::new();
+        tags.insert("Deal".to_string(), "E:AKQ.AKQ.AKQ.AKQJT98765432 ... ... ...".to_string());
+        assert_eq!(deal_starting_seat(&board, Some(&tags)), Some(Direction::East));
+    }
+
+    #[test]
+    fn test_deal_starting_seat_falls_back_to_dealer() {
+        let mut board = Board::default();
+        board.dealer = Some(Direction::South);
+        assert_eq!(deal_starting_seat(&board, None), Some(Direction::South));
+    }
+
     #[test]
     fn test_rotate_direction() {
         assert_eq!(rotate_direction(Direction::North, 0), Direction::North);
@@ -687,6 +1067,65 @@ mod tests {
         assert_eq!(rotate_direction(Direction::East, 1), Direction::South);
     }
 
+    #[test]
+    fn test_rotate_direction_wraps_negative_and_out_of_range_counts() {
+        assert_eq!(rotate_direction(Direction::North, -1), Direction::West);
+        assert_eq!(rotate_direction(Direction::North, 4), Direction::North);
+        assert_eq!(rotate_direction(Direction::North, 7), Direction::West);
+        assert_eq!(rotate_direction(Direction::North, -4), Direction::North);
+        assert_eq!(rotate_direction(Direction::East, -1), Direction::North);
+    }
+
+    #[test]
+    fn test_direction_ext_next_prev() {
+        assert_eq!(Direction::North.next(), Direction::East);
+        assert_eq!(Direction::West.next(), Direction::North);
+        assert_eq!(Direction::North.prev(), Direction::West);
+        assert_eq!(Direction::East.prev(), Direction::North);
+    }
+
+    #[test]
+    fn test_direction_ext_partner() {
+        assert_eq!(Direction::North.partner(), Direction::South);
+        assert_eq!(Direction::South.partner(), Direction::North);
+        assert_eq!(Direction::East.partner(), Direction::West);
+        assert_eq!(Direction::West.partner(), Direction::East);
+    }
+
+    #[test]
+    fn test_direction_ext_lho_rho_opponents() {
+        assert_eq!(Direction::North.lho(), Direction::East);
+        assert_eq!(Direction::North.rho(), Direction::West);
+        assert_eq!(Direction::North.opponents(), [Direction::East, Direction::West]);
+    }
+
+    #[test]
+    fn test_direction_ext_is_ns_is_ew() {
+        assert!(Direction::North.is_ns());
+        assert!(Direction::South.is_ns());
+        assert!(!Direction::North.is_ew());
+        assert!(Direction::East.is_ew());
+        assert!(Direction::West.is_ew());
+        assert!(!Direction::East.is_ns());
+    }
+
+    #[test]
+    fn test_rotate_board_swaps_vulnerability_on_odd_rotations_via_is_ns() {
+        let mut board = Board::default();
+        board.dealer = Some(Direction::North);
+        board.vulnerable = Vulnerability::NorthSouth;
+
+        rotate_board(&mut board, 1, false);
+        assert_eq!(board.vulnerable, Vulnerability::EastWest);
+
+        let mut board = Board::default();
+        board.dealer = Some(Direction::North);
+        board.vulnerable = Vulnerability::NorthSouth;
+
+        rotate_board(&mut board, 2, false);
+        assert_eq!(board.vulnerable, Vulnerability::NorthSouth);
+    }
+
     #[test]
     fn test_rotate_score_value() {
         assert_eq!(rotate_score_value("NS 420", 0), "NS 420");
@@ -695,6 +1134,36 @@ mod tests {
         assert_eq!(rotate_score_value("EW -100", 1), "NS -100");
     }
 
+    #[test]
+    fn test_rotate_score_value_wraps_negative_and_out_of_range_counts() {
+        assert_eq!(rotate_score_value("NS 420", -1), "EW 420");
+        assert_eq!(rotate_score_value("NS 420", 4), "NS 420");
+        assert_eq!(rotate_score_value("NS 420", 7), "EW 420");
+    }
+
+    #[test]
+    fn test_rotate_vulnerability_swaps_ns_ew_on_odd_rotations() {
+        assert_eq!(rotate_vulnerability("NS", 0), "NS");
+        assert_eq!(rotate_vulnerability("NS", 1), "EW");
+        assert_eq!(rotate_vulnerability("EW", 1), "NS");
+        assert_eq!(rotate_vulnerability("NS", 2), "NS");
+    }
+
+    #[test]
+    fn test_rotate_vulnerability_leaves_none_and_both_fixed() {
+        assert_eq!(rotate_vulnerability("None", 1), "None");
+        assert_eq!(rotate_vulnerability("Love", 1), "Love");
+        assert_eq!(rotate_vulnerability("Both", 1), "Both");
+        assert_eq!(rotate_vulnerability("All", 3), "All");
+    }
+
+    #[test]
+    fn test_rotate_vulnerability_wraps_negative_and_out_of_range_counts() {
+        assert_eq!(rotate_vulnerability("NS", -1), "EW");
+        assert_eq!(rotate_vulnerability("NS", 4), "NS");
+        assert_eq!(rotate_vulnerability("NS", 7), "EW");
+    }
+
     #[test]
     fn test_rotate_commentary() {
         assert_eq!(
@@ -706,4 +1175,78 @@ mod tests {
             "South and North"
         );
     }
+
+    #[test]
+    fn test_rotate_commentary_wraps_negative_and_out_of_range_counts() {
+        assert_eq!(rotate_commentary("North leads", -2), "South leads");
+        assert_eq!(rotate_commentary("North leads", 4), "North leads");
+        assert_eq!(rotate_commentary("North leads", 5), "East leads");
+    }
+
+    #[test]
+    fn test_rotate_commentary_preserves_case() {
+        assert_eq!(rotate_commentary("NORTH leads", 2), "SOUTH leads");
+        assert_eq!(rotate_commentary("north leads", 2), "south leads");
+        assert_eq!(rotate_commentary("North leads", 2), "South leads");
+    }
+
+    #[test]
+    fn test_rotate_commentary_single_letter_abbreviations() {
+        assert_eq!(rotate_commentary("N leads, W discards", 1), "E leads, N discards");
+        assert_eq!(rotate_commentary("n leads", 1), "e leads");
+    }
+
+    #[test]
+    fn test_rotate_commentary_possessive_and_plural() {
+        assert_eq!(rotate_commentary("North's hand", 2), "South's hand");
+        assert_eq!(rotate_commentary("Easts discard early", 2), "Wests discard early");
+    }
+
+    #[test]
+    fn test_rotate_commentary_leaves_lookalike_words_alone() {
+        assert_eq!(rotate_commentary("Northampton is not Northampton", 2), "Northampton is not Northampton");
+        assert_eq!(rotate_commentary("it's a easy hand", 1), "it's a easy hand");
+    }
+
+    #[test]
+    fn test_rotate_board_ties_dealer_vulnerability_and_hands_together() {
+        let mut board = Board::default();
+        board.dealer = Some(Direction::North);
+        board.vulnerable = Vulnerability::NorthSouth;
+
+        let mut north = Hand::new();
+        north.spades = Holding::from_ranks([Rank::Ace]);
+        board.deal.set_hand(Direction::North, north);
+
+        rotate_board(&mut board, 1, false);
+
+        assert_eq!(board.dealer, Some(Direction::East));
+        assert_eq!(board.vulnerable, Vulnerability::EastWest);
+        assert_eq!(board.deal.hand(Direction::East).spades.ranks, vec![Rank::Ace]);
+        assert!(board.deal.hand(Direction::North).spades.ranks.is_empty());
+    }
+
+    #[test]
+    fn test_suit_line_sorts_high_to_low() {
+        let mut holding = Holding::default();
+        holding.ranks = vec![Rank::Two, Rank::Ace, Rank::King, Rank::Nine];
+        assert_eq!(suit_line('\u{2660}', &holding), "\u{2660} AK92");
+    }
+
+    #[test]
+    fn test_detect_line_ending_crlf() {
+        assert_eq!(detect_line_ending("[Event \"x\"]\r\n[Board \"1\"]\r\n"), "\r\n");
+    }
+
+    #[test]
+    fn test_detect_line_ending_lf() {
+        assert_eq!(detect_line_ending("[Event \"x\"]\n[Board \"1\"]\n"), "\n");
+    }
+
+    #[test]
+    fn test_suit_line_void() {
+        let holding = Holding::default();
+        assert_eq!(suit_line('\u{2665}', &holding), "\u{2665} -");
+    }
+
 }
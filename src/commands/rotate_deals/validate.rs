@@ -0,0 +1,192 @@
+//! Deal-integrity validation: every hand has exactly 13 cards, the four
+//! hands together form exactly one French deck, and no hand repeats a rank
+//! within a suit.
+
+use bridge_parsers::model::{Board, Direction, Rank};
+use std::collections::HashSet;
+
+const ALL_RANKS: [Rank; 13] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+    Rank::Five,
+    Rank::Four,
+    Rank::Three,
+    Rank::Two,
+];
+
+const SUITS: [char; 4] = ['\u{2660}', '\u{2665}', '\u{2666}', '\u{2663}']; // S H D C
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DealError {
+    WrongHandSize { direction: Direction, count: usize },
+    DuplicateInHand { direction: Direction, suit: char, rank: char },
+    DuplicateCard { suit: char, rank: char },
+    MissingCard { suit: char, rank: char },
+}
+
+impl std::fmt::Display for DealError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DealError::WrongHandSize { direction, count } => {
+                write!(f, "{} has {} cards", super::direction_name(*direction), count)
+            }
+            DealError::DuplicateInHand { direction, suit, rank } => {
+                write!(f, "{} holds {}{} twice", direction.to_char(), suit, rank)
+            }
+            DealError::DuplicateCard { suit, rank } => write!(f, "{}{} duplicated", suit, rank),
+            DealError::MissingCard { suit, rank } => write!(f, "{}{} missing", suit, rank),
+        }
+    }
+}
+
+impl std::error::Error for DealError {}
+
+/// Validate that `board` is a legal 52-card deal: each hand holds exactly 13
+/// cards, every card appears exactly once across the four hands, and no hand
+/// repeats a rank within a suit. Returns every problem found, not just the
+/// first one.
+pub fn validate_deal(board: &Board) -> Result<(), Vec<DealError>> {
+    let mut errors = Vec::new();
+    let mut card_counts: std::collections::HashMap<(char, char), u32> =
+        std::collections::HashMap::new();
+
+    for direction in Direction::all() {
+        let hand = board.deal.hand(direction);
+        let holdings = [
+            (SUITS[0], &hand.spades),
+            (SUITS[1], &hand.hearts),
+            (SUITS[2], &hand.diamonds),
+            (SUITS[3], &hand.clubs),
+        ];
+
+        let count: usize = holdings.iter().map(|(_, h)| h.ranks.len()).sum();
+        if count != 13 {
+            errors.push(DealError::WrongHandSize { direction, count });
+        }
+
+        for (suit, holding) in holdings {
+            let mut seen = HashSet::new();
+            for rank in &holding.ranks {
+                if !seen.insert(*rank) {
+                    errors.push(DealError::DuplicateInHand {
+                        direction,
+                        suit,
+                        rank: rank.to_char(),
+                    });
+                }
+                *card_counts.entry((suit, rank.to_char())).or_insert(0) += 1;
+            }
+        }
+    }
+
+    for suit in SUITS {
+        for rank in ALL_RANKS {
+            match card_counts.get(&(suit, rank.to_char())).copied().unwrap_or(0) {
+                0 => errors.push(DealError::MissingCard { suit, rank: rank.to_char() }),
+                1 => {}
+                _ => errors.push(DealError::DuplicateCard { suit, rank: rank.to_char() }),
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Join a board's validation errors into a single comma-separated line,
+/// e.g. "West has 12 cards, ♠K duplicated".
+pub fn describe_errors(errors: &[DealError]) -> String {
+    errors
+        .iter()
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bridge_parsers::model::{Deal, Hand, Holding};
+
+    fn full_deck_deal() -> Deal {
+        let mut deal = Deal::default();
+        let mut hand = Hand::new();
+        hand.spades = Holding::from_ranks([
+            Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten,
+            Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six, Rank::Five,
+            Rank::Four, Rank::Three, Rank::Two,
+        ]);
+        deal.set_hand(Direction::North, hand);
+
+        let mut hand = Hand::new();
+        hand.hearts = Holding::from_ranks([
+            Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten,
+            Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six, Rank::Five,
+            Rank::Four, Rank::Three, Rank::Two,
+        ]);
+        deal.set_hand(Direction::East, hand);
+
+        let mut hand = Hand::new();
+        hand.diamonds = Holding::from_ranks([
+            Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten,
+            Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six, Rank::Five,
+            Rank::Four, Rank::Three, Rank::Two,
+        ]);
+        deal.set_hand(Direction::South, hand);
+
+        let mut hand = Hand::new();
+        hand.clubs = Holding::from_ranks([
+            Rank::Ace, Rank::King, Rank::Queen, Rank::Jack, Rank::Ten,
+            Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six, Rank::Five,
+            Rank::Four, Rank::Three, Rank::Two,
+        ]);
+        deal.set_hand(Direction::West, hand);
+
+        deal
+    }
+
+    #[test]
+    fn test_valid_deal() {
+        let mut board = Board::default();
+        board.deal = full_deck_deal();
+        assert!(validate_deal(&board).is_ok());
+    }
+
+    #[test]
+    fn test_missing_card_and_wrong_hand_size() {
+        let mut board = Board::default();
+        board.deal = full_deck_deal();
+        // Drop North's spade ace, creating both a missing card (the spade
+        // ace) and a wrong hand size (North holds 12).
+        let mut north = board.deal.hand(Direction::North).clone();
+        north.spades.ranks.retain(|r| *r != Rank::Ace);
+        board.deal.set_hand(Direction::North, north);
+
+        let errors = validate_deal(&board).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(e, DealError::WrongHandSize { direction: Direction::North, count: 12 })));
+        assert!(errors.iter().any(|e| matches!(e, DealError::MissingCard { .. })));
+    }
+
+    #[test]
+    fn test_duplicate_in_hand() {
+        let mut board = Board::default();
+        board.deal = full_deck_deal();
+        let mut north = board.deal.hand(Direction::North).clone();
+        north.spades.ranks.push(Rank::Ace);
+        board.deal.set_hand(Direction::North, north);
+
+        let errors = validate_deal(&board).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(e, DealError::DuplicateInHand { .. })));
+        assert!(errors.iter().any(|e| matches!(e, DealError::DuplicateCard { .. })));
+    }
+}
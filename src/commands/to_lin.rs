@@ -1,6 +1,9 @@
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use clap::Args as ClapArgs;
-use pbn_to_pdf::model::{Auction, Board, Call, Direction, Hand, PlaySequence, Suit, Vulnerability};
+use pbn_to_pdf::model::{
+    AnnotatedCall, Auction, Board, Call, Deal, Direction, Hand, Holding, PlaySequence, Players,
+    Rank, Strain, Suit, Trick, Vulnerability,
+};
 use pbn_to_pdf::parser::parse_pbn;
 use std::path::PathBuf;
 
@@ -15,6 +18,65 @@ pub struct Args {
     pub output: Option<PathBuf>,
 }
 
+/// Arguments for the `lin2pbn` subcommand
+#[derive(ClapArgs)]
+pub struct Lin2PbnArgs {
+    /// Input LIN file (one board per line)
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Output PBN file (defaults to <input>.pbn)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// All 13 ranks in descending order, used both for hand decoding and for
+/// inferring the implied fourth hand.
+const ALL_RANKS: [Rank; 13] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+    Rank::Five,
+    Rank::Four,
+    Rank::Three,
+    Rank::Two,
+];
+
+pub fn run_lin2pbn(args: Lin2PbnArgs) -> Result<()> {
+    let content = std::fs::read_to_string(&args.input)
+        .with_context(|| format!("Failed to read input file: {}", args.input.display()))?;
+
+    let boards: Vec<Board> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            decode_lin_to_board(line)
+                .with_context(|| format!("Failed to decode LIN board on line {}", i + 1))
+        })
+        .collect::<Result<_>>()?;
+
+    let pbn_content: String = boards.iter().map(board_to_pbn).collect();
+
+    let output_path = args
+        .output
+        .unwrap_or_else(|| args.input.with_extension("pbn"));
+
+    std::fs::write(&output_path, &pbn_content)
+        .with_context(|| format!("Failed to write output file: {}", output_path.display()))?;
+
+    println!("Decoded {} boards from LIN format", boards.len());
+    println!("Wrote to {}", output_path.display());
+
+    Ok(())
+}
+
 pub fn run(args: Args) -> Result<()> {
     // Read and parse input file
     let content = std::fs::read_to_string(&args.input)
@@ -182,6 +244,331 @@ fn encode_play(parts: &mut Vec<String>, play: &PlaySequence) {
     }
 }
 
+/// Decode a single LIN-encoded board back into a `Board`.
+///
+/// Tags we don't need (`ah`, `pg`, `nt`, and anything unrecognized) are
+/// skipped rather than rejected, since different LIN sources pad the stream
+/// with presentation-only tags.
+fn decode_lin_to_board(lin: &str) -> Result<Board> {
+    // Keep empty values (e.g. "pg||") so tags and values stay paired.
+    let tokens: Vec<&str> = lin.split('|').collect();
+
+    let mut board = Board::default();
+    let mut calls: Vec<AnnotatedCall> = Vec::new();
+    let mut cards: Vec<(Suit, Rank)> = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let tag = tokens[i];
+        let value = tokens.get(i + 1).copied().unwrap_or("");
+        i += 2;
+
+        match tag {
+            "pn" => decode_players(value, &mut board),
+            "md" => decode_deal(value, &mut board)?,
+            "sv" => board.vulnerable = decode_vulnerability(value)?,
+            "mb" => calls.push(decode_call(value)?),
+            "an" => {
+                if let Some(last) = calls.last_mut() {
+                    last.annotation = Some(value.replace('+', " "));
+                }
+            }
+            "pc" => cards.push(decode_card(value)?),
+            "ah" | "pg" | "nt" => {}
+            _ => {}
+        }
+    }
+
+    if !calls.is_empty() {
+        board.auction = Some(Auction { calls });
+    }
+
+    if !cards.is_empty() {
+        board.play = Some(PlaySequence { tricks: cards_to_tricks(&cards) });
+    }
+
+    Ok(board)
+}
+
+fn decode_players(value: &str, board: &mut Board) {
+    let names: Vec<&str> = value.split(',').collect();
+    let mut get = |idx: usize| names.get(idx).filter(|s| !s.is_empty()).map(|s| s.to_string());
+    board.players = Players {
+        south: get(0),
+        west: get(1),
+        north: get(2),
+        east: get(3),
+    };
+}
+
+/// Decode the `md|` tag: a dealer digit (1=S, 2=W, 3=N, 4=E) followed by the
+/// South, West and North hands, with East inferred as the 52 remaining cards.
+fn decode_deal(value: &str, board: &mut Board) -> Result<()> {
+    let mut chars = value.chars();
+    let dealer_digit = chars.next().ok_or_else(|| anyhow!("empty md| value"))?;
+    board.dealer = Some(match dealer_digit {
+        '1' => Direction::South,
+        '2' => Direction::West,
+        '3' => Direction::North,
+        '4' => Direction::East,
+        other => return Err(anyhow!("invalid dealer digit '{}' in md| tag", other)),
+    });
+
+    let rest: String = chars.collect();
+    let hands: Vec<&str> = rest.split(',').collect();
+    if hands.len() < 3 {
+        return Err(anyhow!("md| tag must list South, West and North hands"));
+    }
+
+    let south = decode_hand(hands[0])?;
+    let west = decode_hand(hands[1])?;
+    let north = decode_hand(hands[2])?;
+    let east = infer_remaining_hand(&[&south, &west, &north]);
+
+    let mut deal = Deal::default();
+    deal.set_hand(Direction::South, south);
+    deal.set_hand(Direction::West, west);
+    deal.set_hand(Direction::North, north);
+    deal.set_hand(Direction::East, east);
+    board.deal = deal;
+
+    Ok(())
+}
+
+/// Decode a single LIN hand string like "SAKQHJT9D876C5432" (SHDC order,
+/// suit letters omitted when the suit is void).
+fn decode_hand(value: &str) -> Result<Hand> {
+    let mut hand = Hand::new();
+    let mut current: Option<Suit> = None;
+
+    for c in value.chars() {
+        match c {
+            'S' => current = Some(Suit::Spades),
+            'H' => current = Some(Suit::Hearts),
+            'D' => current = Some(Suit::Diamonds),
+            'C' => current = Some(Suit::Clubs),
+            _ => {
+                let rank = rank_from_char(c)
+                    .ok_or_else(|| anyhow!("invalid rank '{}' in LIN hand", c))?;
+                match current {
+                    Some(Suit::Spades) => hand.spades.ranks.push(rank),
+                    Some(Suit::Hearts) => hand.hearts.ranks.push(rank),
+                    Some(Suit::Diamonds) => hand.diamonds.ranks.push(rank),
+                    Some(Suit::Clubs) => hand.clubs.ranks.push(rank),
+                    None => return Err(anyhow!("rank '{}' with no preceding suit letter", c)),
+                }
+            }
+        }
+    }
+
+    Ok(hand)
+}
+
+/// The fourth hand is never sent over LIN; reconstruct it as whatever cards
+/// the other three hands don't hold, suit by suit.
+fn infer_remaining_hand(known: &[&Hand; 3]) -> Hand {
+    let mut hand = Hand::new();
+    for (get, set): (fn(&Hand) -> &Holding, fn(&mut Hand) -> &mut Holding) in [
+        (
+            (|h: &Hand| &h.spades) as fn(&Hand) -> &Holding,
+            (|h: &mut Hand| &mut h.spades) as fn(&mut Hand) -> &mut Holding,
+        ),
+        (
+            (|h: &Hand| &h.hearts) as fn(&Hand) -> &Holding,
+            (|h: &mut Hand| &mut h.hearts) as fn(&mut Hand) -> &mut Holding,
+        ),
+        (
+            (|h: &Hand| &h.diamonds) as fn(&Hand) -> &Holding,
+            (|h: &mut Hand| &mut h.diamonds) as fn(&mut Hand) -> &mut Holding,
+        ),
+        (
+            (|h: &Hand| &h.clubs) as fn(&Hand) -> &Holding,
+            (|h: &mut Hand| &mut h.clubs) as fn(&mut Hand) -> &mut Holding,
+        ),
+    ] {
+        let used: Vec<Rank> = known.iter().flat_map(|h| get(h).ranks.iter().copied()).collect();
+        let remaining: Vec<Rank> = ALL_RANKS.iter().copied().filter(|r| !used.contains(r)).collect();
+        set(&mut hand).ranks = remaining;
+    }
+    hand
+}
+
+fn rank_from_char(c: char) -> Option<Rank> {
+    match c.to_ascii_uppercase() {
+        'A' => Some(Rank::Ace),
+        'K' => Some(Rank::King),
+        'Q' => Some(Rank::Queen),
+        'J' => Some(Rank::Jack),
+        'T' => Some(Rank::Ten),
+        '9' => Some(Rank::Nine),
+        '8' => Some(Rank::Eight),
+        '7' => Some(Rank::Seven),
+        '6' => Some(Rank::Six),
+        '5' => Some(Rank::Five),
+        '4' => Some(Rank::Four),
+        '3' => Some(Rank::Three),
+        '2' => Some(Rank::Two),
+        _ => None,
+    }
+}
+
+fn decode_vulnerability(value: &str) -> Result<Vulnerability> {
+    match value {
+        "o" => Ok(Vulnerability::None),
+        "n" => Ok(Vulnerability::NorthSouth),
+        "e" => Ok(Vulnerability::EastWest),
+        "b" => Ok(Vulnerability::Both),
+        other => Err(anyhow!("invalid sv| value '{}'", other)),
+    }
+}
+
+/// Decode an `mb|` value like "1C", "p", "d", "r", optionally with a
+/// trailing "!" alert marker.
+fn decode_call(value: &str) -> Result<AnnotatedCall> {
+    let (value, alerted) = match value.strip_suffix('!') {
+        Some(stripped) => (stripped, true),
+        None => (value, false),
+    };
+
+    let call = match value.to_ascii_lowercase().as_str() {
+        "p" => Call::Pass,
+        "d" => Call::Double,
+        "r" => Call::Redouble,
+        _ => {
+            let mut chars = value.chars();
+            let level_char = chars.next().ok_or_else(|| anyhow!("empty mb| value"))?;
+            let level = level_char
+                .to_digit(10)
+                .ok_or_else(|| anyhow!("invalid bid level '{}'", level_char))? as u8;
+            let strain_char = chars.next().ok_or_else(|| anyhow!("bid missing strain"))?;
+            let strain = Strain::from_char(strain_char)
+                .ok_or_else(|| anyhow!("invalid strain '{}'", strain_char))?;
+            Call::Bid { level, strain }
+        }
+    };
+
+    // The alert marker doesn't carry annotation text on its own; an `!` with
+    // no following `an|` tag just means "alerted, no note given".
+    let annotation = if alerted { Some(String::new()) } else { None };
+    Ok(AnnotatedCall { call, annotation })
+}
+
+fn decode_card(value: &str) -> Result<(Suit, Rank)> {
+    let mut chars = value.chars();
+    let suit_char = chars.next().ok_or_else(|| anyhow!("empty pc| value"))?;
+    let suit = match suit_char {
+        'S' => Suit::Spades,
+        'H' => Suit::Hearts,
+        'D' => Suit::Diamonds,
+        'C' => Suit::Clubs,
+        other => return Err(anyhow!("invalid suit '{}' in pc| tag", other)),
+    };
+    let rank_char = chars.next().ok_or_else(|| anyhow!("pc| value missing rank"))?;
+    let rank = rank_from_char(rank_char).ok_or_else(|| anyhow!("invalid rank '{}'", rank_char))?;
+    Ok((suit, rank))
+}
+
+fn cards_to_tricks(cards: &[(Suit, Rank)]) -> Vec<Trick> {
+    cards
+        .chunks(4)
+        .map(|chunk| {
+            let mut trick_cards: [Option<pbn_to_pdf::model::Card>; 4] = [None, None, None, None];
+            for (i, (suit, rank)) in chunk.iter().enumerate() {
+                trick_cards[i] = Some(pbn_to_pdf::model::Card { suit: *suit, rank: *rank });
+            }
+            Trick { cards: trick_cards }
+        })
+        .collect()
+}
+
+/// Serialize a decoded `Board` back to PBN text.
+fn board_to_pbn(board: &Board) -> String {
+    let mut out = String::new();
+    out.push_str("[Event \"\"]\n");
+    out.push_str("[Site \"\"]\n");
+    out.push_str("[Date \"\"]\n");
+    out.push_str(&format!("[Board \"{}\"]\n", board.number.unwrap_or(0)));
+    out.push_str(&format!("[West \"{}\"]\n", board.players.west.as_deref().unwrap_or("")));
+    out.push_str(&format!("[North \"{}\"]\n", board.players.north.as_deref().unwrap_or("")));
+    out.push_str(&format!("[East \"{}\"]\n", board.players.east.as_deref().unwrap_or("")));
+    out.push_str(&format!("[South \"{}\"]\n", board.players.south.as_deref().unwrap_or("")));
+
+    let dealer = board.dealer.unwrap_or(Direction::North);
+    out.push_str(&format!("[Dealer \"{}\"]\n", dealer.to_char()));
+    out.push_str(&format!("[Vulnerable \"{}\"]\n", board.vulnerable.to_pbn()));
+    out.push_str(&format!("[Deal \"{}\"]\n", board.deal.to_pbn(dealer)));
+
+    if let Some(ref auction) = board.auction {
+        out.push_str(&format!("[Auction \"{}\"]\n", dealer.to_char()));
+        // Footnotes collected while rendering calls, so they can be emitted as
+        // `[Note "n:..."]` tags right after the auction: PBN's standard
+        // mechanism for attaching an annotation to a call, e.g. the `!` alert
+        // markers and note text LIN's `mb|...|an|...|` pair decodes into
+        // `AnnotatedCall.annotation`.
+        let mut notes: Vec<&str> = Vec::new();
+        for chunk in auction.calls.chunks(4) {
+            let row: Vec<String> = chunk
+                .iter()
+                .map(|ac| match &ac.annotation {
+                    Some(annotation) => {
+                        notes.push(annotation);
+                        format!("{} ={}=", call_to_pbn(&ac.call), notes.len())
+                    }
+                    None => call_to_pbn(&ac.call),
+                })
+                .collect();
+            out.push_str(&row.join(" "));
+            out.push('\n');
+        }
+        for (i, note) in notes.iter().enumerate() {
+            out.push_str(&format!("[Note \"{}:{}\"]\n", i + 1, note));
+        }
+    }
+
+    if let Some(ref play) = board.play {
+        let leader = rotate_clockwise(dealer, 1);
+        out.push_str(&format!("[Play \"{}\"]\n", leader.to_char()));
+        for trick in &play.tricks {
+            let row: Vec<String> = trick
+                .cards
+                .iter()
+                .flatten()
+                .map(|card| format!("{}{}", suit_char(card.suit), card.rank.to_char()))
+                .collect();
+            out.push_str(&row.join(" "));
+            out.push('\n');
+        }
+    }
+
+    out.push('\n');
+    out
+}
+
+fn call_to_pbn(call: &Call) -> String {
+    match call {
+        Call::Pass => "P".to_string(),
+        Call::Double => "X".to_string(),
+        Call::Redouble => "XX".to_string(),
+        Call::Continue => "-".to_string(),
+        Call::Bid { level, strain } => format!("{}{}", level, strain.to_char().to_ascii_uppercase()),
+    }
+}
+
+fn suit_char(suit: Suit) -> char {
+    match suit {
+        Suit::Spades => 'S',
+        Suit::Hearts => 'H',
+        Suit::Diamonds => 'D',
+        Suit::Clubs => 'C',
+    }
+}
+
+fn rotate_clockwise(dir: Direction, n: u8) -> Direction {
+    let order = [Direction::North, Direction::East, Direction::South, Direction::West];
+    let idx = order.iter().position(|d| *d == dir).unwrap_or(0);
+    order[(idx + n as usize) % 4]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,4 +618,81 @@ mod tests {
             "o"
         );
     }
+
+    #[test]
+    fn test_decode_hand() {
+        let hand = decode_hand("SAKQHJT9D876C5432").unwrap();
+        assert_eq!(hand.spades.ranks, vec![Rank::Ace, Rank::King, Rank::Queen]);
+        assert_eq!(hand.hearts.ranks, vec![Rank::Jack, Rank::Ten, Rank::Nine]);
+        assert_eq!(hand.diamonds.ranks, vec![Rank::Eight, Rank::Seven, Rank::Six]);
+        assert_eq!(
+            hand.clubs.ranks,
+            vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two]
+        );
+    }
+
+    #[test]
+    fn test_decode_vulnerability() {
+        assert_eq!(decode_vulnerability("o").unwrap(), Vulnerability::None);
+        assert_eq!(decode_vulnerability("n").unwrap(), Vulnerability::NorthSouth);
+        assert_eq!(decode_vulnerability("b").unwrap(), Vulnerability::Both);
+        assert!(decode_vulnerability("x").is_err());
+    }
+
+    #[test]
+    fn test_decode_call_with_alert() {
+        let ac = decode_call("1C!").unwrap();
+        assert!(matches!(ac.call, Call::Bid { level: 1, strain: _ }));
+        assert!(ac.annotation.is_some());
+    }
+
+    #[test]
+    fn test_decode_call_pass() {
+        let ac = decode_call("p").unwrap();
+        assert!(matches!(ac.call, Call::Pass));
+        assert!(ac.annotation.is_none());
+    }
+
+    #[test]
+    fn test_infer_remaining_hand() {
+        let south = decode_hand("SAKQHJT9D876C5432").unwrap();
+        let west = Hand::new();
+        let north = Hand::new();
+        let east = infer_remaining_hand(&[&south, &west, &north]);
+        // South holds 13 cards, so East (with West/North void) has the other 39
+        let total: usize = [&east.spades, &east.hearts, &east.diamonds, &east.clubs]
+            .iter()
+            .map(|h| h.ranks.len())
+            .sum();
+        assert_eq!(total, 39);
+    }
+
+    #[test]
+    fn test_decode_lin_reads_dealer_vulnerability_and_players() {
+        let lin = "pn|S,W,N,E|md|3SAKQJT9876543,H23,D23|sv|o|";
+        let board = decode_lin_to_board(lin).unwrap();
+        assert_eq!(board.dealer, Some(Direction::North));
+        assert_eq!(board.vulnerable, Vulnerability::None);
+        assert_eq!(board.players.south, Some("S".to_string()));
+    }
+
+    #[test]
+    fn test_board_to_pbn_preserves_call_annotation_as_footnote() {
+        let lin = "pn|S,W,N,E|md|3SAKQJT9876543,H23,D23|sv|o|mb|1C!|an|Strong+club|mb|p|";
+        let board = decode_lin_to_board(lin).unwrap();
+        let pbn = board_to_pbn(&board);
+
+        assert!(pbn.contains("1C =1= P"));
+        assert!(pbn.contains("[Note \"1:Strong club\"]"));
+    }
+
+    #[test]
+    fn test_board_to_pbn_omits_footnote_for_unannotated_calls() {
+        let lin = "pn|S,W,N,E|md|3SAKQJT9876543,H23,D23|sv|o|mb|1C|mb|p|";
+        let board = decode_lin_to_board(lin).unwrap();
+        let pbn = board_to_pbn(&board);
+
+        assert!(!pbn.contains("=1="));
+        assert!(!pbn.contains("[Note "));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::board_range::parse_board_range;
 use anyhow::{Context, Result};
 use clap::{Args as ClapArgs, ValueEnum};
 use pbn_to_pdf::cli::Layout as PdfLayout;
@@ -47,7 +48,8 @@ pub struct Args {
     #[arg(short, long)]
     pub boards_per_page: Option<u8>,
 
-    /// Board range to include (e.g., "1-4" or "1,3,5")
+    /// Board selection (e.g., "1-4", "1,3,5", "5-", "-8", "odd", "even", "all",
+    /// or "1-16,!7,!13" to exclude specific boards)
     #[arg(short = 'r', long)]
     pub board_range: Option<String>,
 
@@ -85,7 +87,8 @@ pub fn run(args: Args) -> Result<()> {
 
     // Filter boards if range specified
     let boards = if let Some(ref range) = args.board_range {
-        let allowed = parse_board_range(range)?;
+        let present: Vec<u32> = pbn_file.boards.iter().filter_map(|b| b.number).collect();
+        let allowed = parse_board_range(range, &present)?;
         pbn_file
             .boards
             .into_iter()
@@ -156,48 +159,3 @@ pub fn run(args: Args) -> Result<()> {
     Ok(())
 }
 
-/// Parse a board range specification like "1-4" or "1,3,5" or "1-4,7,9-12"
-fn parse_board_range(range: &str) -> Result<Vec<u32>> {
-    let mut boards = Vec::new();
-
-    for part in range.split(',') {
-        let part = part.trim();
-        if part.contains('-') {
-            let parts: Vec<&str> = part.split('-').collect();
-            if parts.len() != 2 {
-                return Err(anyhow::anyhow!("Invalid range: {}", part));
-            }
-            let start: u32 = parts[0]
-                .trim()
-                .parse()
-                .with_context(|| format!("Invalid number in range: {}", parts[0]))?;
-            let end: u32 = parts[1]
-                .trim()
-                .parse()
-                .with_context(|| format!("Invalid number in range: {}", parts[1]))?;
-            for i in start..=end {
-                boards.push(i);
-            }
-        } else {
-            let num: u32 = part
-                .parse()
-                .with_context(|| format!("Invalid board number: {}", part))?;
-            boards.push(num);
-        }
-    }
-
-    Ok(boards)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_board_range() {
-        assert_eq!(parse_board_range("1-4").unwrap(), vec![1, 2, 3, 4]);
-        assert_eq!(parse_board_range("1,3,5").unwrap(), vec![1, 3, 5]);
-        assert_eq!(parse_board_range("1-3,7").unwrap(), vec![1, 2, 3, 7]);
-        assert_eq!(parse_board_range("1").unwrap(), vec![1]);
-    }
-}
@@ -0,0 +1,239 @@
+//! Parsing and legality-checking for the PBN deal-string format used in a
+//! board's `Deal` tag: `"<dir>:<hand> <hand> <hand> <hand>"`, where each
+//! hand is four dot-separated suit groups (spades.hearts.diamonds.clubs)
+//! and hands are listed starting from `<dir>` and proceeding clockwise.
+
+use anyhow::{anyhow, Context, Result};
+use bridge_parsers::model::{Deal, Direction, Hand, Holding, Rank};
+use std::collections::HashMap;
+
+const ALL_RANKS: [Rank; 13] = [
+    Rank::Ace,
+    Rank::King,
+    Rank::Queen,
+    Rank::Jack,
+    Rank::Ten,
+    Rank::Nine,
+    Rank::Eight,
+    Rank::Seven,
+    Rank::Six,
+    Rank::Five,
+    Rank::Four,
+    Rank::Three,
+    Rank::Two,
+];
+
+const SUITS: [char; 4] = ['\u{2660}', '\u{2665}', '\u{2666}', '\u{2663}']; // S H D C
+
+/// Parse a PBN deal string into a `Deal`, checking that it's legal: exactly
+/// four hands of exactly four suit groups, only valid rank characters,
+/// exactly 13 cards per hand, and no card missing or duplicated across the
+/// whole deal.
+pub fn parse_deal(s: &str) -> Result<Deal> {
+    let (start_str, hands_str) = s
+        .split_once(':')
+        .with_context(|| format!("deal \"{}\" is missing a leading direction (e.g. \"N:...\")", s))?;
+    let start_char = start_str
+        .chars()
+        .next()
+        .ok_or_else(|| anyhow!("deal \"{}\" has an empty starting direction", s))?;
+    let start = Direction::from_char(start_char)
+        .ok_or_else(|| anyhow!("deal \"{}\" has an invalid starting direction '{}'", s, start_char))?;
+
+    let hand_strs: Vec<&str> = hands_str.split_whitespace().collect();
+    if hand_strs.len() != 4 {
+        return Err(anyhow!("deal \"{}\" has {} hands, expected 4", s, hand_strs.len()));
+    }
+
+    let mut deal = Deal::default();
+    let mut card_counts: HashMap<(char, char), u32> = HashMap::new();
+    let mut direction = start;
+
+    for hand_str in hand_strs {
+        let suit_groups: Vec<&str> = hand_str.split('.').collect();
+        if suit_groups.len() != 4 {
+            return Err(anyhow!(
+                "{} holding \"{}\" has {} suit groups, expected 4",
+                direction.to_char(),
+                hand_str,
+                suit_groups.len()
+            ));
+        }
+
+        let mut hand = Hand::new();
+        let mut card_count = 0;
+
+        for (suit, group) in SUITS.iter().zip(&suit_groups) {
+            let mut ranks = Vec::new();
+            for c in group.chars() {
+                let rank = rank_from_char(c)
+                    .ok_or_else(|| anyhow!("{} holds an invalid card '{}{}'", direction.to_char(), suit, c))?;
+                ranks.push(rank);
+                *card_counts.entry((*suit, rank.to_char())).or_insert(0) += 1;
+            }
+            card_count += ranks.len();
+            let holding = Holding::from_ranks(ranks);
+            match *suit {
+                '\u{2660}' => hand.spades = holding,
+                '\u{2665}' => hand.hearts = holding,
+                '\u{2666}' => hand.diamonds = holding,
+                '\u{2663}' => hand.clubs = holding,
+                _ => unreachable!("SUITS only contains the four suit symbols"),
+            }
+        }
+
+        if card_count != 13 {
+            return Err(anyhow!("{} has {} cards, expected 13", direction.to_char(), card_count));
+        }
+
+        deal.set_hand(direction, hand);
+        direction = next_clockwise(direction);
+    }
+
+    for suit in SUITS {
+        for rank in ALL_RANKS {
+            match card_counts.get(&(suit, rank.to_char())).copied().unwrap_or(0) {
+                1 => {}
+                0 => return Err(anyhow!("deal \"{}\" is missing {}{}", s, suit, rank.to_char())),
+                _ => return Err(anyhow!("deal \"{}\" has {}{} more than once", s, suit, rank.to_char())),
+            }
+        }
+    }
+
+    Ok(deal)
+}
+
+/// Render `deal` as a canonical PBN deal string: always starting at North,
+/// with each hand's cards sorted high to low. Two deals that hold the same
+/// physical cards canonicalize to the same string regardless of which
+/// direction or card order their original text used, which is what makes
+/// `wrangle diff` order-insensitive.
+pub fn canonicalize_deal(deal: &Deal) -> String {
+    let hands: Vec<String> = [Direction::North, Direction::East, Direction::South, Direction::West]
+        .iter()
+        .map(|&dir| {
+            let hand = deal.hand(dir);
+            [&hand.spades, &hand.hearts, &hand.diamonds, &hand.clubs]
+                .iter()
+                .map(|holding| sorted_rank_string(&holding.ranks))
+                .collect::<Vec<_>>()
+                .join(".")
+        })
+        .collect();
+    format!("N:{}", hands.join(" "))
+}
+
+fn sorted_rank_string(ranks: &[Rank]) -> String {
+    let mut sorted = ranks.to_vec();
+    sorted.sort_by_key(|r| rank_order(*r));
+    sorted.iter().map(|r| r.to_char()).collect()
+}
+
+fn rank_order(rank: Rank) -> usize {
+    ALL_RANKS.iter().position(|r| *r == rank).unwrap()
+}
+
+fn rank_from_char(c: char) -> Option<Rank> {
+    match c.to_ascii_uppercase() {
+        'A' => Some(Rank::Ace),
+        'K' => Some(Rank::King),
+        'Q' => Some(Rank::Queen),
+        'J' => Some(Rank::Jack),
+        'T' => Some(Rank::Ten),
+        '9' => Some(Rank::Nine),
+        '8' => Some(Rank::Eight),
+        '7' => Some(Rank::Seven),
+        '6' => Some(Rank::Six),
+        '5' => Some(Rank::Five),
+        '4' => Some(Rank::Four),
+        '3' => Some(Rank::Three),
+        '2' => Some(Rank::Two),
+        _ => None,
+    }
+}
+
+fn next_clockwise(dir: Direction) -> Direction {
+    match dir {
+        Direction::North => Direction::East,
+        Direction::East => Direction::South,
+        Direction::South => Direction::West,
+        Direction::West => Direction::North,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL_DECK_DEAL: &str =
+        "N:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432";
+
+    #[test]
+    fn test_parse_deal_accepts_a_legal_deal() {
+        assert!(parse_deal(FULL_DECK_DEAL).is_ok());
+    }
+
+    #[test]
+    fn test_parse_deal_rejects_missing_direction() {
+        let err = parse_deal("AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432")
+            .unwrap_err();
+        assert!(err.to_string().contains("leading direction"));
+    }
+
+    #[test]
+    fn test_parse_deal_rejects_wrong_hand_count() {
+        let err = parse_deal("N:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432.").unwrap_err();
+        assert!(err.to_string().contains("expected 4"));
+    }
+
+    #[test]
+    fn test_parse_deal_rejects_wrong_card_count() {
+        let err = parse_deal("N:AKQJT9876543... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432")
+            .unwrap_err();
+        assert!(err.to_string().contains("cards, expected 13"));
+    }
+
+    #[test]
+    fn test_parse_deal_rejects_duplicate_card() {
+        // West's clubs repeat the ace of clubs instead of holding the two.
+        let err = parse_deal(
+            "N:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT9876543A",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("more than once"));
+    }
+
+    #[test]
+    fn test_parse_deal_rejects_invalid_rank() {
+        let err = parse_deal("N:AKQJT9876543Z... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432")
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid card"));
+    }
+
+    #[test]
+    fn test_parse_deal_starts_hands_at_the_stated_direction() {
+        let deal = parse_deal("E:AKQJT98765432... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432")
+            .unwrap();
+        assert_eq!(deal.hand(Direction::East).spades.ranks.len(), 13);
+        assert_eq!(deal.hand(Direction::South).hearts.ranks.len(), 13);
+    }
+
+    #[test]
+    fn test_canonicalize_deal_ignores_starting_direction() {
+        let starting_at_north = parse_deal(FULL_DECK_DEAL).unwrap();
+        let starting_at_east = parse_deal(
+            "E:.AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432 AKQJT98765432...",
+        )
+        .unwrap();
+        assert_eq!(canonicalize_deal(&starting_at_north), canonicalize_deal(&starting_at_east));
+    }
+
+    #[test]
+    fn test_canonicalize_deal_sorts_ranks_high_to_low() {
+        let shuffled = parse_deal(
+            "N:23456789TJQKA... .AKQJT98765432.. ..AKQJT98765432. ...AKQJT98765432",
+        )
+        .unwrap();
+        assert_eq!(canonicalize_deal(&shuffled), canonicalize_deal(&parse_deal(FULL_DECK_DEAL).unwrap()));
+    }
+}
@@ -1,7 +1,12 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
+mod board_range;
 mod commands;
+mod deal_text;
+mod pbn_document;
+#[cfg(test)]
+mod test_support;
 
 #[derive(Parser)]
 #[command(name = "bridge-wrangler")]
@@ -19,6 +24,8 @@ enum Commands {
     ToPdf(commands::to_pdf::Args),
     /// Convert PBN file to LIN format
     ToLin(commands::to_lin::Args),
+    /// Convert LIN file back to PBN format
+    Lin2Pbn(commands::to_lin::Lin2PbnArgs),
     /// Perform double-dummy analysis on deals
     Analyze(commands::analyze::Args),
     /// Replicate boards into blocks for multi-table play
@@ -27,6 +34,12 @@ enum Commands {
     Filter(commands::filter::Args),
     /// Update the Event tag for all boards
     Event(commands::event::Args),
+    /// Validate a PBN file against a set of dealing-machine-friendly rules
+    Lint(commands::lint::Args),
+    /// Check that every board's Deal tag is a legal 52-card deal
+    CheckDeals(commands::check_deals::Args),
+    /// Compare two PBN files by canonicalized deal, ignoring board numbering
+    Diff(commands::diff::Args),
 }
 
 fn main() -> Result<()> {
@@ -36,9 +49,13 @@ fn main() -> Result<()> {
         Commands::RotateDeals(args) => commands::rotate_deals::run(args),
         Commands::ToPdf(args) => commands::to_pdf::run(args),
         Commands::ToLin(args) => commands::to_lin::run(args),
+        Commands::Lin2Pbn(args) => commands::to_lin::run_lin2pbn(args),
         Commands::Analyze(args) => commands::analyze::run(args),
         Commands::BlockReplicate(args) => commands::block_replicate::run(args),
         Commands::Filter(args) => commands::filter::run(args),
         Commands::Event(args) => commands::event::run(args),
+        Commands::Lint(args) => commands::lint::run(args),
+        Commands::CheckDeals(args) => commands::check_deals::run(args),
+        Commands::Diff(args) => commands::diff::run(args),
     }
 }
@@ -0,0 +1,209 @@
+//! A structured, order-preserving model of PBN (Portable Bridge Notation)
+//! text, for commands that need to read or rewrite tags without resorting
+//! to regex surgery on the raw file.
+//!
+//! Parsing never fails: anything that isn't a recognized `[Tag "Value"]`
+//! line is kept verbatim as either document-level header text or a board's
+//! leading/trailing lines, so `PbnDocument::parse` followed by
+//! `PbnDocument::serialize` round-trips well-formed input byte-for-byte.
+
+/// One board (game record): its tags in original order, plus whatever free
+/// text surrounds them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PbnBoard {
+    /// The run of `%`/`;` comment lines (and any blank lines after or among
+    /// them) directly above this board's first tag. Kept separate from the previous board's trailer so
+    /// a board that opens with a comment (e.g. `% Board 5: squeeze`) stays
+    /// attached to the board it actually introduces.
+    pub leading: Vec<String>,
+    /// Tags in file order, as `(name, value)` pairs.
+    pub tags: Vec<(String, String)>,
+    /// Everything after the last tag: auction rows, play rows, braced
+    /// commentary, trailing blank lines - preserved verbatim.
+    pub trailer: Vec<String>,
+}
+
+impl PbnBoard {
+    /// The value of `name`, if this board has that tag.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Set a tag's value, updating it in place if present or appending it to
+    /// the end of the tag block otherwise.
+    pub fn set_tag(&mut self, name: &str, value: impl Into<String>) {
+        let value = value.into();
+        match self.tags.iter_mut().find(|(n, _)| n == name) {
+            Some(entry) => entry.1 = value,
+            None => self.tags.push((name.to_string(), value)),
+        }
+    }
+
+    /// Render this board back to PBN text, including its leading and
+    /// trailing lines.
+    pub fn serialize(&self) -> String {
+        let mut out = String::new();
+        for line in &self.leading {
+            out.push_str(line);
+            out.push('\n');
+        }
+        for (name, value) in &self.tags {
+            out.push('[');
+            out.push_str(name);
+            out.push_str(" \"");
+            out.push_str(value);
+            out.push_str("\"]\n");
+        }
+        for line in &self.trailer {
+            out.push_str(line);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// A full PBN file: free-form text before the first board, plus the
+/// ordered boards that follow it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PbnDocument {
+    /// Lines before the first board's first tag (file-level comments,
+    /// blank lines, `%`-directives).
+    pub header: String,
+    pub boards: Vec<PbnBoard>,
+}
+
+impl PbnDocument {
+    /// Parse PBN text into a document. A new board begins at an `Event` tag
+    /// once at least one board already exists, matching how every PBN board
+    /// record starts with its Event tag; unlike naive string-splitting on
+    /// that tag, comment lines immediately before it (and blank lines between
+    /// them and the tag) are attributed to the board they precede rather than
+    /// left dangling on the previous one.
+    pub fn parse(content: &str) -> PbnDocument {
+        let mut header = String::new();
+        let mut boards: Vec<PbnBoard> = Vec::new();
+        let mut pending: Vec<String> = Vec::new();
+
+        for line in content.lines() {
+            if let Some((name, value)) = parse_tag_line(line.trim()) {
+                if name == "Event" && !boards.is_empty() {
+                    // Only the run of comment lines directly above this tag
+                    // (blank lines between them included) introduces the new
+                    // board; everything before it closes out the previous one.
+                    let is_comment_or_blank = |l: &String| {
+                        let t = l.trim();
+                        t.is_empty() || t.starts_with('%') || t.starts_with(';')
+                    };
+                    let mut split = pending.len();
+                    while split > 0 && is_comment_or_blank(&pending[split - 1]) {
+                        split -= 1;
+                    }
+                    while split < pending.len() && pending[split].trim().is_empty() {
+                        split += 1;
+                    }
+                    let leading = pending.split_off(split);
+                    boards.last_mut().unwrap().trailer.extend(std::mem::take(&mut pending));
+                    boards.push(PbnBoard { leading, ..PbnBoard::default() });
+                } else if boards.is_empty() {
+                    boards.push(PbnBoard {
+                        leading: std::mem::take(&mut pending),
+                        ..PbnBoard::default()
+                    });
+                } else if !pending.is_empty() {
+                    // A non-tag line slipped in between two tags of the same
+                    // board (malformed input); keep it rather than drop it.
+                    boards.last_mut().unwrap().trailer.extend(std::mem::take(&mut pending));
+                }
+                boards
+                    .last_mut()
+                    .unwrap()
+                    .tags
+                    .push((name.to_string(), value.to_string()));
+            } else if boards.is_empty() {
+                header.push_str(line);
+                header.push('\n');
+            } else {
+                pending.push(line.to_string());
+            }
+        }
+
+        match boards.last_mut() {
+            Some(board) => board.trailer.extend(pending),
+            None => {
+                for line in pending {
+                    header.push_str(&line);
+                    header.push('\n');
+                }
+            }
+        }
+
+        PbnDocument { header, boards }
+    }
+
+    /// Render this document back to PBN text.
+    pub fn serialize(&self) -> String {
+        let mut out = self.header.clone();
+        for board in &self.boards {
+            out.push_str(&board.serialize());
+        }
+        out
+    }
+}
+
+/// Parse a single `[Name "Value"]` tag line. Returns `None` for anything
+/// else (comments, blank lines, auction/play rows, malformed tags).
+fn parse_tag_line(line: &str) -> Option<(&str, &str)> {
+    let inner = line.strip_prefix('[')?.strip_suffix(']')?;
+    let (name, rest) = inner.split_once(' ')?;
+    let value = rest.trim().strip_prefix('"')?.strip_suffix('"')?;
+    Some((name, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_well_formed_input() {
+        let content = "% generated by dealer\n\n[Event \"Club Championship\"]\n[Board \"1\"]\n[Dealer \"N\"]\n[Deal \"N:...\"]\n\n[Event \"Club Championship\"]\n[Board \"2\"]\n[Dealer \"E\"]\n[Deal \"N:...\"]\n{some commentary}\n";
+        let document = PbnDocument::parse(content);
+        assert_eq!(document.serialize(), content);
+    }
+
+    #[test]
+    fn test_parse_splits_boards_on_event_tag() {
+        let content = "[Event \"A\"]\n[Board \"1\"]\n\n[Event \"A\"]\n[Board \"2\"]\n";
+        let document = PbnDocument::parse(content);
+        assert_eq!(document.boards.len(), 2);
+        assert_eq!(document.boards[0].tag("Board"), Some("1"));
+        assert_eq!(document.boards[1].tag("Board"), Some("2"));
+    }
+
+    #[test]
+    fn test_comment_before_board_attaches_to_that_board_not_the_previous_one() {
+        let content = "[Event \"A\"]\n[Board \"1\"]\n\n% Board 2: tricky squeeze\n[Event \"A\"]\n[Board \"2\"]\n";
+        let document = PbnDocument::parse(content);
+        assert!(document.boards[0].trailer.iter().all(|l| !l.contains("tricky squeeze")));
+        assert_eq!(
+            document.boards[1].leading,
+            vec!["% Board 2: tricky squeeze".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_set_tag_updates_existing_and_appends_new() {
+        let mut board = PbnBoard::default();
+        board.set_tag("Event", "Old");
+        board.set_tag("Board", "1");
+        board.set_tag("Event", "New");
+
+        assert_eq!(board.tag("Event"), Some("New"));
+        assert_eq!(board.tags, vec![
+            ("Event".to_string(), "New".to_string()),
+            ("Board".to_string(), "1".to_string()),
+        ]);
+    }
+}
@@ -0,0 +1,69 @@
+//! Fixture-file comparison helper shared by golden-output tests. Each
+//! fixture is a pair of checked-in files, `<name>_input.pbn` and
+//! `<name>_expected.pbn`, under `tests/fixtures/<group>/`.
+
+use std::path::PathBuf;
+
+fn fixture_path(group: &str, name: &str, suffix: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(group)
+        .join(format!("{}_{}.pbn", name, suffix))
+}
+
+pub fn read_fixture(group: &str, name: &str) -> String {
+    let path = fixture_path(group, name, "input");
+    std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read fixture {}: {}", path.display(), e))
+}
+
+/// Normalize line endings so the comparison doesn't depend on how the
+/// fixture file was checked out.
+fn normalize(content: &str) -> String {
+    content.replace("\r\n", "\n")
+}
+
+/// Assert that `actual` matches the checked-in `<name>_expected.pbn` file in
+/// `tests/fixtures/<group>/`, comparing literal text (after normalizing line
+/// endings) rather than byte-for-byte.
+///
+/// This is a literal comparison, not a structural one, and that's
+/// intentional: `PbnBoard` keeps tags in an order-preserving `Vec<(String,
+/// String)>` rather than a `HashMap`, so parsing and reserializing a board
+/// never reorders its tags or otherwise changes its formatting. There's no
+/// cosmetic drift for a structural comparison to usefully absorb here, so a
+/// literal comparison is strictly stronger: it also catches an emission-order
+/// regression (e.g. a tag moving earlier or later in a replicated board)
+/// that a structural comparison would silently swallow.
+///
+/// Set `BLESS=1` to rewrite the expected file with `actual` instead of
+/// failing, for use after a legitimate behavior change.
+pub fn assert_matches_fixture(group: &str, name: &str, actual: &str) {
+    assert_matches_fixture_with(group, name, actual, |content| normalize(content))
+}
+
+fn assert_matches_fixture_with(
+    group: &str,
+    name: &str,
+    actual: &str,
+    canonicalize: impl Fn(&str) -> String,
+) {
+    let path = fixture_path(group, name, "expected");
+
+    if std::env::var("BLESS").is_ok() {
+        std::fs::write(&path, actual)
+            .unwrap_or_else(|e| panic!("failed to bless fixture {}: {}", path.display(), e));
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read fixture {}: {}", path.display(), e));
+
+    assert_eq!(
+        canonicalize(actual),
+        canonicalize(&expected),
+        "{} does not match {} (rerun with BLESS=1 to update it if this change is intentional)",
+        name,
+        path.display()
+    );
+}